@@ -1,3 +1,5 @@
+use std::{env, fs, path::PathBuf};
+
 use esp_build::assert_unique_used_features;
 
 fn main() {
@@ -13,9 +15,123 @@ fn main() {
         panic!("Only one of `custom-halt` and `halt-cores` can be enabled");
     }
 
+    if cfg!(feature = "reset") && (cfg!(feature = "custom-halt") || cfg!(feature = "halt-cores")) {
+        panic!("The `reset` feature can't be combined with `custom-halt` or `halt-cores`");
+    }
+
+    if cfg!(feature = "reset") && cfg!(feature = "esp32p4") {
+        panic!("The `reset` feature is not supported on the ESP32-P4 yet");
+    }
+
+    if cfg!(feature = "semihosting-output") && cfg!(feature = "defmt") {
+        panic!("The `semihosting-output` feature can only be used with the `println` backend");
+    }
+
+    if cfg!(feature = "panic-count") && (cfg!(feature = "esp32c2") || cfg!(feature = "esp32p4")) {
+        panic!("The `panic-count` feature is not supported on targets without RTC fast memory");
+    }
+
+    generate_dram_range();
+    generate_frame_format();
+    generate_panic_exit_code();
+
     check_nightly();
 }
 
+// Additional DRAM address range accepted when validating stack frames, e.g. for
+// stacks placed in PSRAM
+fn generate_dram_range() {
+    println!("cargo:rerun-if-env-changed=ESP_BACKTRACE_CONFIG_DRAM_LOW");
+    println!("cargo:rerun-if-env-changed=ESP_BACKTRACE_CONFIG_DRAM_HIGH");
+
+    let low = env::var("ESP_BACKTRACE_CONFIG_DRAM_LOW").ok();
+    let high = env::var("ESP_BACKTRACE_CONFIG_DRAM_HIGH").ok();
+
+    let range = match (low, high) {
+        (None, None) => String::from("None"),
+        (Some(low), Some(high)) => {
+            let low = parse_address("ESP_BACKTRACE_CONFIG_DRAM_LOW", &low);
+            let high = parse_address("ESP_BACKTRACE_CONFIG_DRAM_HIGH", &high);
+            if low > high {
+                panic!("`ESP_BACKTRACE_CONFIG_DRAM_LOW` must not be greater than `ESP_BACKTRACE_CONFIG_DRAM_HIGH`");
+            }
+            format!("Some(({low:#x}, {high:#x}))")
+        }
+        _ => panic!(
+            "Both or none of `ESP_BACKTRACE_CONFIG_DRAM_LOW` and `ESP_BACKTRACE_CONFIG_DRAM_HIGH` must be set"
+        ),
+    };
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(
+        out.join("dram_range.rs"),
+        format!("const EXTRA_DRAM_RANGE: Option<(u32, u32)> = {range};\n"),
+    )
+    .unwrap();
+}
+
+// Format of a single backtrace frame. This is a Rust format string referring to
+// the frame's address as `pc`.
+fn generate_frame_format() {
+    println!("cargo:rerun-if-env-changed=ESP_BACKTRACE_CONFIG_FRAME_FORMAT");
+
+    let format = match env::var("ESP_BACKTRACE_CONFIG_FRAME_FORMAT") {
+        Ok(format) => {
+            if cfg!(feature = "defmt") {
+                panic!("`ESP_BACKTRACE_CONFIG_FRAME_FORMAT` is not supported with the `defmt` backend");
+            }
+            if !format.contains("{pc") {
+                panic!("`ESP_BACKTRACE_CONFIG_FRAME_FORMAT` must contain a `{{pc}}` placeholder");
+            }
+            format
+        }
+        Err(_) => String::from("0x{pc:x}"),
+    };
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(
+        out.join("frame_format.rs"),
+        format!("macro_rules! frame_format {{\n    () => {{\n        {format:?}\n    }};\n}}\n"),
+    )
+    .unwrap();
+}
+
+// Exit code reported to the semihosting host when panicking with the
+// `panic-exit-code` feature
+fn generate_panic_exit_code() {
+    println!("cargo:rerun-if-env-changed=ESP_BACKTRACE_CONFIG_PANIC_EXIT_CODE");
+
+    let code = match env::var("ESP_BACKTRACE_CONFIG_PANIC_EXIT_CODE") {
+        Ok(code) => {
+            let code: i32 = code.trim().parse().unwrap_or_else(|_| {
+                panic!("`ESP_BACKTRACE_CONFIG_PANIC_EXIT_CODE` is not a valid exit code: {code}")
+            });
+            if code == 0 {
+                panic!("`ESP_BACKTRACE_CONFIG_PANIC_EXIT_CODE` must not be zero");
+            }
+            code
+        }
+        Err(_) => 101,
+    };
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(
+        out.join("panic_exit_code.rs"),
+        format!("const PANIC_EXIT_CODE: i32 = {code};\n"),
+    )
+    .unwrap();
+}
+
+fn parse_address(name: &str, value: &str) -> u32 {
+    let value = value.trim();
+    let parsed = match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(&hex.replace('_', ""), 16),
+        None => value.replace('_', "").parse(),
+    };
+
+    parsed.unwrap_or_else(|_| panic!("`{name}` is not a valid address: {value}"))
+}
+
 #[rustversion::all(not(stable),not(since(2024-06-12)))]
 fn check_nightly() {
     println!("cargo:rustc-cfg=nightly_before_2024_06_12");
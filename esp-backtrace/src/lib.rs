@@ -15,6 +15,11 @@ use esp_println as _;
 
 const MAX_BACKTRACE_ADDRESSES: usize = 10;
 
+include!(concat!(env!("OUT_DIR"), "/dram_range.rs"));
+include!(concat!(env!("OUT_DIR"), "/frame_format.rs"));
+#[cfg(feature = "panic-exit-code")]
+include!(concat!(env!("OUT_DIR"), "/panic_exit_code.rs"));
+
 #[cfg(feature = "colors")]
 const RESET: &str = "\u{001B}[0m";
 #[cfg(feature = "colors")]
@@ -32,9 +37,43 @@ macro_rules! println {
 
 #[cfg(all(feature = "println", not(feature = "defmt")))]
 macro_rules! println {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
         esp_println::println!($($arg)*);
-    };
+
+        #[cfg(feature = "semihosting-output")]
+        {
+            use semihosting::io::Write as _;
+
+            // Errors are ignored - there is nothing sensible to do about them
+            // while panicking
+            if let Ok(mut stdout) = semihosting::io::stdout() {
+                let _ = writeln!(stdout, $($arg)*);
+            }
+        }
+    }};
+}
+
+// Print a single backtrace frame, using the format configured via
+// `ESP_BACKTRACE_CONFIG_FRAME_FORMAT` for the `println` backend
+macro_rules! print_frame {
+    ($pc:expr) => {{
+        #[cfg(all(feature = "colors", feature = "println"))]
+        println!(concat!("{}", frame_format!()), RED, pc = $pc);
+
+        #[cfg(all(not(feature = "colors"), feature = "println"))]
+        println!(frame_format!(), pc = $pc);
+
+        #[cfg(feature = "defmt")]
+        println!("0x{:x}", $pc);
+    }};
+}
+
+#[allow(unused)]
+fn print_truncated() {
+    println!(
+        "... (backtrace truncated after {} frames)",
+        MAX_BACKTRACE_ADDRESSES
+    );
 }
 
 #[allow(unused, unused_variables)]
@@ -52,12 +91,71 @@ pub mod arch;
 #[cfg(feature = "panic-handler")]
 #[panic_handler]
 fn panic_handler(info: &core::panic::PanicInfo) -> ! {
+    #[cfg(feature = "panic-count")]
+    increment_panic_count();
+
     #[cfg(feature = "colors")]
     set_color_code(RED);
 
     println!("");
     println!("");
 
+    #[cfg(feature = "custom-panic-format")]
+    let print_message = {
+        extern "Rust" {
+            fn custom_panic_format(info: &core::panic::PanicInfo) -> bool;
+        }
+        unsafe { custom_panic_format(info) }
+    };
+
+    #[cfg(not(feature = "custom-panic-format"))]
+    let print_message = true;
+
+    if print_message {
+        print_panic_message(info);
+    }
+
+    #[cfg(feature = "custom-panic-context")]
+    {
+        extern "Rust" {
+            fn custom_panic_context(info: &core::panic::PanicInfo);
+        }
+        unsafe { custom_panic_context(info) }
+    }
+
+    println!("");
+    println!("Backtrace:");
+    println!("");
+
+    let (backtrace, truncated) = crate::arch::backtrace_with_truncation();
+    #[cfg(target_arch = "riscv32")]
+    if backtrace.iter().filter(|e| e.is_some()).count() == 0 {
+        println!("No backtrace available - make sure to force frame-pointers. (see https://crates.io/crates/esp-backtrace)");
+    }
+    for e in backtrace {
+        if let Some(addr) = e {
+            print_frame!(addr - crate::arch::RA_OFFSET);
+        }
+    }
+    if truncated {
+        print_truncated();
+    }
+
+    #[cfg(feature = "colors")]
+    set_color_code(RESET);
+
+    #[cfg(feature = "panic-exit-code")]
+    semihosting::process::exit(PANIC_EXIT_CODE);
+
+    #[cfg(all(feature = "semihosting", not(feature = "panic-exit-code")))]
+    semihosting::process::abort();
+
+    #[cfg(not(feature = "semihosting"))]
+    halt();
+}
+
+#[cfg(feature = "panic-handler")]
+fn print_panic_message(info: &core::panic::PanicInfo) {
     if let Some(location) = info.location() {
         let (file, line, column) = (location.file(), location.line(), location.column());
         println!(
@@ -97,34 +195,6 @@ fn panic_handler(info: &core::panic::PanicInfo) -> ! {
         #[cfg(feature = "defmt")]
         println!("{}", defmt::Display2Format(&message));
     }
-
-    println!("");
-    println!("Backtrace:");
-    println!("");
-
-    let backtrace = crate::arch::backtrace();
-    #[cfg(target_arch = "riscv32")]
-    if backtrace.iter().filter(|e| e.is_some()).count() == 0 {
-        println!("No backtrace available - make sure to force frame-pointers. (see https://crates.io/crates/esp-backtrace)");
-    }
-    for e in backtrace {
-        if let Some(addr) = e {
-            #[cfg(all(feature = "colors", feature = "println"))]
-            println!("{}0x{:x}", RED, addr - crate::arch::RA_OFFSET);
-
-            #[cfg(not(all(feature = "colors", feature = "println")))]
-            println!("0x{:x}", addr - crate::arch::RA_OFFSET);
-        }
-    }
-
-    #[cfg(feature = "colors")]
-    set_color_code(RESET);
-
-    #[cfg(feature = "semihosting")]
-    semihosting::process::abort();
-
-    #[cfg(not(feature = "semihosting"))]
-    halt();
 }
 
 #[cfg(all(feature = "exception-handler", target_arch = "xtensa"))]
@@ -143,12 +213,15 @@ unsafe fn __user_exception(cause: arch::ExceptionCause, context: arch::Context)
 
     println!("{:?}", context);
 
-    let backtrace = crate::arch::backtrace_internal(context.A1, 0);
+    let (backtrace, truncated) = crate::arch::backtrace_internal(context.A1, 0);
     for e in backtrace {
         if let Some(addr) = e {
-            println!("0x{:x}", addr);
+            print_frame!(addr - crate::arch::RA_OFFSET);
         }
     }
+    if truncated {
+        print_truncated();
+    }
     println!("");
     println!("");
     println!("");
@@ -210,19 +283,18 @@ fn exception_handler(context: &arch::TrapFrame) -> ! {
         #[cfg(feature = "defmt")]
         println!("{:?}", context);
 
-        let backtrace = crate::arch::backtrace_internal(context.s0 as u32, 0);
+        let (backtrace, truncated) = crate::arch::backtrace_internal(context.s0 as u32, 0);
         if backtrace.iter().filter(|e| e.is_some()).count() == 0 {
             println!("No backtrace available - make sure to force frame-pointers. (see https://crates.io/crates/esp-backtrace)");
         }
         for e in backtrace {
             if let Some(addr) = e {
-                #[cfg(all(feature = "colors", feature = "println"))]
-                println!("{}0x{:x}", RED, addr - crate::arch::RA_OFFSET);
-
-                #[cfg(not(all(feature = "colors", feature = "println")))]
-                println!("0x{:x}", addr - crate::arch::RA_OFFSET);
+                print_frame!(addr - crate::arch::RA_OFFSET);
             }
         }
+        if truncated {
+            print_truncated();
+        }
     }
 
     println!("");
@@ -239,6 +311,59 @@ fn exception_handler(context: &arch::TrapFrame) -> ! {
     halt();
 }
 
+#[cfg(feature = "panic-count")]
+const PANIC_COUNT_MAGIC: u32 = 0x5041_4e43;
+
+// Holds a marker and the number of panics, retained across resets other than
+// power-on.
+#[cfg(feature = "panic-count")]
+#[link_section = ".rtc_fast.noinit"]
+static mut PANIC_COUNT: core::mem::MaybeUninit<[u32; 2]> = core::mem::MaybeUninit::uninit();
+
+#[cfg(feature = "panic-count")]
+fn panic_count_storage() -> *mut [u32; 2] {
+    extern "C" {
+        fn rtc_get_reset_reason(cpu_no: u32) -> u32;
+    }
+
+    // 1 is `POWERON_RESET` on all chips
+    let power_on = unsafe { rtc_get_reset_reason(0) } == 1;
+
+    let storage = unsafe { core::ptr::addr_of_mut!(PANIC_COUNT) as *mut [u32; 2] };
+    unsafe {
+        if power_on || storage.read_volatile()[0] != PANIC_COUNT_MAGIC {
+            storage.write_volatile([PANIC_COUNT_MAGIC, 0]);
+        }
+    }
+
+    storage
+}
+
+#[cfg(feature = "panic-count")]
+fn increment_panic_count() {
+    let storage = panic_count_storage();
+    unsafe {
+        let [magic, count] = storage.read_volatile();
+        storage.write_volatile([magic, count.saturating_add(1)]);
+    }
+}
+
+/// Get the number of panics since the last power-on reset.
+///
+/// The counter is incremented by the panic handler and kept in RTC fast
+/// memory, so it survives software and watchdog resets. It is cleared on
+/// power-on and by [reset_panic_count].
+#[cfg(feature = "panic-count")]
+pub fn panic_count() -> u32 {
+    unsafe { panic_count_storage().read_volatile()[1] }
+}
+
+/// Reset the number of panics returned by [panic_count] to zero.
+#[cfg(feature = "panic-count")]
+pub fn reset_panic_count() {
+    unsafe { panic_count_storage().write_volatile([PANIC_COUNT_MAGIC, 0]) };
+}
+
 // Ensure that the address is in DRAM and that it is 16-byte aligned.
 //
 // Based loosely on the `esp_stack_ptr_in_dram` function from
@@ -246,11 +371,21 @@ fn exception_handler(context: &arch::TrapFrame) -> ! {
 //
 // Address ranges can be found in `components/soc/$CHIP/include/soc/soc.h` as
 // `SOC_DRAM_LOW` and `SOC_DRAM_HIGH`.
+//
+// An additional range can be accepted by setting
+// `ESP_BACKTRACE_CONFIG_DRAM_LOW` and `ESP_BACKTRACE_CONFIG_DRAM_HIGH` at build
+// time.
 fn is_valid_ram_address(address: u32) -> bool {
     if (address & 0xF) != 0 {
         return false;
     }
 
+    if let Some((low, high)) = EXTRA_DRAM_RANGE {
+        if (low..=high).contains(&address) {
+            return true;
+        }
+    }
+
     #[cfg(feature = "esp32")]
     if !(0x3FFA_E000..=0x4000_0000).contains(&address) {
         return false;
@@ -299,7 +434,8 @@ fn is_valid_ram_address(address: u32) -> bool {
         not(any(feature = "esp32", feature = "esp32p4", feature = "esp32s3")),
         not(feature = "halt-cores")
     ),
-    not(feature = "custom-halt")
+    not(feature = "custom-halt"),
+    not(feature = "reset")
 ))]
 #[allow(unused)]
 fn halt() -> ! {
@@ -316,6 +452,18 @@ fn halt() -> ! {
     unsafe { custom_halt() }
 }
 
+#[cfg(feature = "reset")]
+fn halt() -> ! {
+    extern "C" {
+        fn software_reset();
+    }
+    unsafe { software_reset() };
+
+    loop {
+        continue;
+    }
+}
+
 // TODO: Enable `halt` function for `esp32p4` feature once implemented
 #[cfg(all(any(feature = "esp32", feature = "esp32s3"), feature = "halt-cores"))]
 #[allow(unused)]
@@ -120,15 +120,72 @@ pub fn backtrace() -> [Option<usize>; MAX_BACKTRACE_ADDRESSES] {
         _tmp
     };
 
+    backtrace_internal(fp, 2).0
+}
+
+/// Like [backtrace], but also reports whether there were more frames than
+/// fit into the returned array.
+#[allow(unused)]
+pub(crate) fn backtrace_with_truncation() -> ([Option<usize>; MAX_BACKTRACE_ADDRESSES], bool) {
+    let fp = unsafe {
+        let mut _tmp: u32;
+        asm!("mv {0}, x8", out(reg) _tmp);
+        _tmp
+    };
+
     backtrace_internal(fp, 2)
 }
 
+/// Get an array of backtrace addresses, leaving out the first `skip` frames.
+///
+/// This is useful when capturing a backtrace from a helper, e.g. a logging
+/// wrapper, whose own frames aren't of interest.
+///
+/// This needs `force-frame-pointers` enabled.
+pub fn backtrace_skip(skip: usize) -> [Option<usize>; MAX_BACKTRACE_ADDRESSES] {
+    let fp = unsafe {
+        let mut _tmp: u32;
+        asm!("mv {0}, x8", out(reg) _tmp);
+        _tmp
+    };
+
+    backtrace_internal(fp, i32::try_from(skip).unwrap_or(i32::MAX).saturating_add(2)).0
+}
+
+/// Get an array of backtrace addresses, starting to unwind at the given
+/// frame pointer (`s0`/`x8`) instead of the current one.
+///
+/// This is useful to get a backtrace for a different context, e.g. from a
+/// trap frame. Like [backtrace], the returned addresses are return
+/// addresses. An empty backtrace is returned if `fp` doesn't point to RAM.
+///
+/// This needs `force-frame-pointers` enabled.
+pub fn backtrace_from(fp: usize) -> [Option<usize>; MAX_BACKTRACE_ADDRESSES] {
+    if !crate::is_valid_ram_address(fp as u32) {
+        return [None; MAX_BACKTRACE_ADDRESSES];
+    }
+
+    backtrace_internal(fp as u32, 0).0
+}
+
+/// Check whether `address` is a valid stack or frame pointer.
+///
+/// The address needs to be 16-byte aligned and inside the chip's internal
+/// DRAM or the additional range configured via
+/// `ESP_BACKTRACE_CONFIG_DRAM_LOW`/`ESP_BACKTRACE_CONFIG_DRAM_HIGH`. This is
+/// the check the unwinder applies to every frame, which makes it useful for
+/// custom unwinders built on [backtrace_from].
+pub fn is_valid_stack_address(address: u32) -> bool {
+    crate::is_valid_ram_address(address)
+}
+
 pub(crate) fn backtrace_internal(
     fp: u32,
     suppress: i32,
-) -> [Option<usize>; MAX_BACKTRACE_ADDRESSES] {
+) -> ([Option<usize>; MAX_BACKTRACE_ADDRESSES], bool) {
     let mut result = [None; 10];
     let mut index = 0;
+    let mut truncated = false;
 
     let mut fp = fp;
     let mut suppress = suppress;
@@ -153,17 +210,19 @@ pub(crate) fn backtrace_internal(
             }
 
             if suppress == 0 {
-                result[index] = Some(address as usize);
-                index += 1;
-
                 if index >= MAX_BACKTRACE_ADDRESSES {
+                    // There is another valid frame which doesn't fit anymore
+                    truncated = true;
                     break;
                 }
+
+                result[index] = Some(address as usize);
+                index += 1;
             } else {
                 suppress -= 1;
             }
         }
     }
 
-    result
+    (result, truncated)
 }
@@ -278,19 +278,72 @@ pub fn backtrace() -> [Option<usize>; MAX_BACKTRACE_ADDRESSES] {
         _tmp
     };
 
+    backtrace_internal(sp, 1).0
+}
+
+/// Like [backtrace], but also reports whether there were more frames than
+/// fit into the returned array.
+#[allow(unused)]
+pub(crate) fn backtrace_with_truncation() -> ([Option<usize>; MAX_BACKTRACE_ADDRESSES], bool) {
+    let sp = unsafe {
+        let mut _tmp: u32;
+        asm!("mov {0}, a1", out(reg) _tmp);
+        _tmp
+    };
+
     backtrace_internal(sp, 1)
 }
 
+/// Get an array of backtrace addresses, leaving out the first `skip` frames.
+///
+/// This is useful when capturing a backtrace from a helper, e.g. a logging
+/// wrapper, whose own frames aren't of interest.
+pub fn backtrace_skip(skip: usize) -> [Option<usize>; MAX_BACKTRACE_ADDRESSES] {
+    let sp = unsafe {
+        let mut _tmp: u32;
+        asm!("mov {0}, a1", out(reg) _tmp);
+        _tmp
+    };
+
+    backtrace_internal(sp, i32::try_from(skip).unwrap_or(i32::MAX).saturating_add(1)).0
+}
+
+/// Get an array of backtrace addresses, starting to unwind at the given
+/// stack pointer (`a1`) instead of the current one.
+///
+/// This is useful to get a backtrace for a different context, e.g. from a
+/// trap frame. Like [backtrace], the returned addresses are return
+/// addresses. An empty backtrace is returned if `fp` doesn't point to RAM.
+pub fn backtrace_from(fp: usize) -> [Option<usize>; MAX_BACKTRACE_ADDRESSES] {
+    if !crate::is_valid_ram_address(fp as u32) {
+        return [None; MAX_BACKTRACE_ADDRESSES];
+    }
+
+    backtrace_internal(fp as u32, 0).0
+}
+
 pub(crate) fn sanitize_address(address: u32) -> u32 {
     (address & 0x3fff_ffff) | 0x4000_0000
 }
 
+/// Check whether `address` is a valid stack or frame pointer.
+///
+/// The address needs to be 16-byte aligned and inside the chip's internal
+/// DRAM or the additional range configured via
+/// `ESP_BACKTRACE_CONFIG_DRAM_LOW`/`ESP_BACKTRACE_CONFIG_DRAM_HIGH`. This is
+/// the check the unwinder applies to every frame, which makes it useful for
+/// custom unwinders built on [backtrace_from].
+pub fn is_valid_stack_address(address: u32) -> bool {
+    crate::is_valid_ram_address(address)
+}
+
 pub(crate) fn backtrace_internal(
     sp: u32,
     suppress: i32,
-) -> [Option<usize>; MAX_BACKTRACE_ADDRESSES] {
+) -> ([Option<usize>; MAX_BACKTRACE_ADDRESSES], bool) {
     let mut result = [None; 10];
     let mut index = 0;
+    let mut truncated = false;
 
     let mut fp = sp;
     let mut suppress = suppress;
@@ -320,17 +373,19 @@ pub(crate) fn backtrace_internal(
             }
 
             if suppress == 0 {
-                result[index] = Some(address as usize);
-                index += 1;
-
                 if index >= MAX_BACKTRACE_ADDRESSES {
+                    // There is another valid frame which doesn't fit anymore
+                    truncated = true;
                     break;
                 }
+
+                result[index] = Some(address as usize);
+                index += 1;
             } else {
                 suppress -= 1;
             }
         }
     }
 
-    result
+    (result, truncated)
 }
@@ -9,10 +9,11 @@
 //! Debug Assistant is an auxiliary module that features a set of functions to
 //! help locate bugs and issues during software debugging.
 //!
-//! While all the targets support program counter (PC) logging it's API is not
-//! exposed here. Instead the ROM bootloader will always enable it and print the
-//! last seen PC (e.g. _Saved PC:0x42002ff2_). Make sure the reset was triggered
-//! by a TIMG watchdog. Not an RTC or SWD watchdog.
+//! While all the targets support program counter (PC) logging only the last
+//! logged PC is exposed here (see [DebugAssist::last_saved_pc]). The ROM
+//! bootloader will always enable it and print the last seen PC (e.g. _Saved
+//! PC:0x42002ff2_). Make sure the reset was triggered by a TIMG watchdog. Not
+//! an RTC or SWD watchdog.
 //!
 //! ⚠️ Bus write access logging is not available via this API. ⚠️
 //!
@@ -24,6 +25,11 @@ use crate::{
     peripherals::ASSIST_DEBUG,
 };
 
+/// Number of bytes at the end of the stack which are reserved by
+/// [DebugAssist::enable_stack_overflow_guard].
+#[cfg(assist_debug_sp_monitor)]
+pub const STACK_GUARD_SIZE: u32 = 512;
+
 /// The debug assist driver instance.
 pub struct DebugAssist<'d> {
     debug_assist: PeripheralRef<'d, ASSIST_DEBUG>,
@@ -58,6 +64,60 @@ impl<'d> DebugAssist<'d> {
 
         DebugAssist { debug_assist }
     }
+
+    /// Get the PC logged before the last reset.
+    ///
+    /// This is the value printed by the ROM bootloader as _Saved PC_. Returns
+    /// `None` if the last reset wasn't triggered by a TIMG watchdog, since
+    /// the logged value isn't meaningful otherwise.
+    pub fn last_saved_pc(&self) -> Option<u32> {
+        match crate::reset::get_reset_reason() {
+            Some(reason) if is_timg_wdt_reset(reason) => Some(
+                self.debug_assist
+                    .core_0_rcd_pdebugpc()
+                    .read()
+                    .core_0_rcd_pdebugpc()
+                    .bits(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the reset was triggered by one of the TIMG watchdogs.
+#[cfg(esp32c2)]
+fn is_timg_wdt_reset(reason: crate::rtc_cntl::SocResetReason) -> bool {
+    use crate::rtc_cntl::SocResetReason;
+
+    matches!(reason, SocResetReason::CoreMwdt0 | SocResetReason::Cpu0Mwdt0)
+}
+
+/// Whether the reset was triggered by one of the TIMG watchdogs.
+#[cfg(esp32s3)]
+fn is_timg_wdt_reset(reason: crate::rtc_cntl::SocResetReason) -> bool {
+    use crate::rtc_cntl::SocResetReason;
+
+    matches!(
+        reason,
+        SocResetReason::CoreMwdt0
+            | SocResetReason::CoreMwdt1
+            | SocResetReason::CpuMwdt0
+            | SocResetReason::CpuMwdt1
+    )
+}
+
+/// Whether the reset was triggered by one of the TIMG watchdogs.
+#[cfg(not(any(esp32c2, esp32s3)))]
+fn is_timg_wdt_reset(reason: crate::rtc_cntl::SocResetReason) -> bool {
+    use crate::rtc_cntl::SocResetReason;
+
+    matches!(
+        reason,
+        SocResetReason::CoreMwdt0
+            | SocResetReason::CoreMwdt1
+            | SocResetReason::Cpu0Mwdt0
+            | SocResetReason::Cpu0Mwdt1
+    )
 }
 
 #[cfg(assist_debug_sp_monitor)]
@@ -91,6 +151,28 @@ impl<'d> DebugAssist<'d> {
         });
     }
 
+    /// Enable SP monitoring on main core using the bounds of the stack
+    /// provided by the linker script.
+    ///
+    /// The lower bound is raised by [STACK_GUARD_SIZE] bytes so the interrupt
+    /// handler still has some stack left to run when the guard is hit. This
+    /// works with and without the `flip-link` feature.
+    pub fn enable_stack_overflow_guard(&mut self) {
+        extern "C" {
+            static _stack_start: u32;
+            static _stack_end: u32;
+        }
+
+        let (stack_start, stack_end) = unsafe {
+            (
+                core::ptr::addr_of!(_stack_start) as u32,
+                core::ptr::addr_of!(_stack_end) as u32,
+            )
+        };
+
+        self.enable_sp_monitor(stack_end + STACK_GUARD_SIZE, stack_start);
+    }
+
     /// Disable SP monitoring on main core.
     pub fn disable_sp_monitor(&mut self) {
         self.debug_assist.core_0_intr_ena().modify(|_, w| {
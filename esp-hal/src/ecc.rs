@@ -52,6 +52,8 @@ pub enum Error {
     SizeMismatchCurve,
     /// It means that the point is not on the curve.
     PointNotOnSelectedCurve,
+    /// It means that the result of the calculation is the point at infinity.
+    PointAtInfinity,
 }
 
 pub enum EllipticCurve {
@@ -86,6 +88,86 @@ impl<'d> Ecc<'d, crate::Blocking> {
     ///
     /// Optionally an interrupt handler can be bound.    
     pub fn new(ecc: impl Peripheral<P = ECC> + 'd, interrupt: Option<InterruptHandler>) -> Self {
+        Self::new_internal(ecc, interrupt)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'d> Ecc<'d, crate::Async> {
+    /// Create a new instance in [crate::Async] mode.
+    pub fn new_async(ecc: impl Peripheral<P = ECC> + 'd) -> Self {
+        Self::new_internal(ecc, Some(asynch::ecc_interrupt_handler))
+    }
+
+    /// # Base point multiplication
+    ///
+    /// Asynchronous version of [Ecc::affine_point_multiplication].
+    pub async fn affine_point_multiplication_async(
+        &mut self,
+        curve: &EllipticCurve,
+        k: &[u8],
+        x: &mut [u8],
+        y: &mut [u8],
+    ) -> Result<(), Error> {
+        // Validate the input before enabling the interrupt
+        key_length(curve, &[k, x, y])?;
+
+        let future = asynch::EccFuture::new(&self.ecc);
+        self.start_affine_point_multiplication(curve, k, x, y)?;
+        future.await;
+
+        self.read_affine_point_multiplication(x, y);
+
+        Ok(())
+    }
+
+    /// # Base Point Verification
+    ///
+    /// Asynchronous version of [Ecc::affine_point_verification].
+    pub async fn affine_point_verification_async(
+        &mut self,
+        curve: &EllipticCurve,
+        x: &[u8],
+        y: &[u8],
+    ) -> Result<(), Error> {
+        // Validate the input before enabling the interrupt
+        key_length(curve, &[x, y])?;
+
+        let future = asynch::EccFuture::new(&self.ecc);
+        self.start_affine_point_verification(curve, x, y)?;
+        future.await;
+
+        self.check_verification_result()
+    }
+
+    /// # Jacobian Point Multiplication
+    ///
+    /// Asynchronous version of [Ecc::jacobian_point_multiplication].
+    pub async fn jacobian_point_multiplication_async(
+        &mut self,
+        curve: &EllipticCurve,
+        k: &mut [u8],
+        x: &mut [u8],
+        y: &mut [u8],
+    ) -> Result<(), Error> {
+        // Validate the input before enabling the interrupt
+        key_length(curve, &[k, x, y])?;
+
+        let future = asynch::EccFuture::new(&self.ecc);
+        self.start_jacobian_point_multiplication(curve, k, x, y)?;
+        future.await;
+
+        self.read_jacobian_point_multiplication(k, x, y);
+
+        Ok(())
+    }
+}
+
+impl<'d, DM: crate::Mode> Ecc<'d, DM> {
+    fn new_internal(
+        ecc: impl Peripheral<P = ECC> + 'd,
+        interrupt: Option<InterruptHandler>,
+    ) -> Self {
         crate::into_ref!(ecc);
 
         PeripheralClockControl::enable(PeripheralEnable::Ecc);
@@ -107,9 +189,7 @@ impl<'d> Ecc<'d, crate::Blocking> {
             phantom: PhantomData,
         }
     }
-}
 
-impl<'d, DM: crate::Mode> Ecc<'d, DM> {
     pub fn reset(&mut self) {
         self.ecc.mult_conf().reset()
     }
@@ -132,51 +212,12 @@ impl<'d, DM: crate::Mode> Ecc<'d, DM> {
         x: &mut [u8],
         y: &mut [u8],
     ) -> Result<(), Error> {
-        let curve = match curve {
-            EllipticCurve::P192 => {
-                if k.len() != 24 || x.len() != 24 || y.len() != 24 {
-                    return Err(Error::SizeMismatchCurve);
-                }
-                false
-            }
-            EllipticCurve::P256 => {
-                if k.len() != 32 || x.len() != 32 || y.len() != 32 {
-                    return Err(Error::SizeMismatchCurve);
-                }
-                true
-            }
-        };
-        let mode = WorkMode::PointMultiMode;
-
-        let mut tmp = [0_u8; 32];
-        self.reverse_words(k, &mut tmp);
-        self.alignment_helper
-            .volatile_write_regset(self.ecc.k_mem(0).as_ptr(), tmp.as_ref(), 8);
-        self.reverse_words(x, &mut tmp);
-        self.alignment_helper
-            .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), tmp.as_ref(), 8);
-        self.reverse_words(y, &mut tmp);
-        self.alignment_helper
-            .volatile_write_regset(self.ecc.py_mem(0).as_ptr(), tmp.as_ref(), 8);
-
-        self.ecc.mult_conf().write(|w| unsafe {
-            w.work_mode()
-                .bits(mode as u8)
-                .key_length()
-                .bit(curve)
-                .start()
-                .set_bit()
-        });
+        self.start_affine_point_multiplication(curve, k, x, y)?;
 
         // wait for interrupt
         while self.is_busy() {}
 
-        self.alignment_helper
-            .volatile_read_regset(self.ecc.px_mem(0).as_ptr(), &mut tmp, 8);
-        self.reverse_words(tmp.as_ref(), x);
-        self.alignment_helper
-            .volatile_read_regset(self.ecc.py_mem(0).as_ptr(), &mut tmp, 8);
-        self.reverse_words(tmp.as_ref(), y);
+        self.read_affine_point_multiplication(x, y);
 
         Ok(())
     }
@@ -260,48 +301,36 @@ impl<'d, DM: crate::Mode> Ecc<'d, DM> {
         x: &[u8],
         y: &[u8],
     ) -> Result<(), Error> {
-        let curve = match curve {
-            EllipticCurve::P192 => {
-                if x.len() != 24 || y.len() != 24 {
-                    return Err(Error::SizeMismatchCurve);
-                }
-                false
-            }
-            EllipticCurve::P256 => {
-                if x.len() != 32 || y.len() != 32 {
-                    return Err(Error::SizeMismatchCurve);
-                }
-                true
-            }
-        };
-        let mode = WorkMode::PointVerif;
-
-        let mut tmp = [0_u8; 32];
-        self.reverse_words(x, &mut tmp);
-        self.alignment_helper
-            .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), tmp.as_ref(), 8);
-        self.reverse_words(y, &mut tmp);
-        self.alignment_helper
-            .volatile_write_regset(self.ecc.py_mem(0).as_ptr(), tmp.as_ref(), 8);
+        if self.is_point_on_curve(curve, x, y)? {
+            Ok(())
+        } else {
+            Err(Error::PointNotOnSelectedCurve)
+        }
+    }
 
-        self.ecc.mult_conf().write(|w| unsafe {
-            w.work_mode()
-                .bits(mode as u8)
-                .key_length()
-                .bit(curve)
-                .start()
-                .set_bit()
-        });
+    /// # Base Point Verification
+    ///
+    /// Checks if a point (Px, Py) is on a selected elliptic curve.
+    ///
+    /// Unlike [Ecc::affine_point_verification] a point which is not on the
+    /// curve is reported as `Ok(false)`.
+    ///
+    /// # Error
+    ///
+    /// This function will return an error if any bitlength value is different
+    /// from the bitlength of the prime fields of the curve.
+    pub fn is_point_on_curve(
+        &mut self,
+        curve: &EllipticCurve,
+        x: &[u8],
+        y: &[u8],
+    ) -> Result<bool, Error> {
+        self.start_affine_point_verification(curve, x, y)?;
 
         // wait for interrupt
         while self.is_busy() {}
 
-        if !self.ecc.mult_conf().read().verification_result().bit() {
-            self.ecc.mult_conf().reset();
-            return Err(Error::PointNotOnSelectedCurve);
-        }
-
-        Ok(())
+        Ok(self.check_verification_result().is_ok())
     }
 
     /// # Base Point Verification + Base Point Multiplication
@@ -491,67 +520,11 @@ impl<'d, DM: crate::Mode> Ecc<'d, DM> {
         x: &mut [u8],
         y: &mut [u8],
     ) -> Result<(), Error> {
-        let curve = match curve {
-            EllipticCurve::P192 => {
-                if k.len() != 24 || x.len() != 24 || y.len() != 24 {
-                    return Err(Error::SizeMismatchCurve);
-                }
-                false
-            }
-            EllipticCurve::P256 => {
-                if k.len() != 32 || x.len() != 32 || y.len() != 32 {
-                    return Err(Error::SizeMismatchCurve);
-                }
-                true
-            }
-        };
-        let mode = WorkMode::JacobianPointMulti;
-
-        let mut tmp = [0_u8; 32];
-        self.reverse_words(k, &mut tmp);
-        self.alignment_helper
-            .volatile_write_regset(self.ecc.k_mem(0).as_ptr(), tmp.as_ref(), 8);
-        self.reverse_words(x, &mut tmp);
-        self.alignment_helper
-            .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), tmp.as_ref(), 8);
-        self.reverse_words(y, &mut tmp);
-        self.alignment_helper
-            .volatile_write_regset(self.ecc.py_mem(0).as_ptr(), tmp.as_ref(), 8);
-
-        self.ecc.mult_conf().write(|w| unsafe {
-            w.work_mode()
-                .bits(mode as u8)
-                .key_length()
-                .bit(curve)
-                .start()
-                .set_bit()
-        });
+        self.start_jacobian_point_multiplication(curve, k, x, y)?;
 
         while self.is_busy() {}
 
-        cfg_if::cfg_if! {
-            if #[cfg(not(esp32h2))] {
-            self.alignment_helper
-                .volatile_read_regset(self.ecc.px_mem(0).as_ptr(), &mut tmp, 8);
-            self.reverse_words(tmp.as_ref(), x);
-            self.alignment_helper
-                .volatile_read_regset(self.ecc.py_mem(0).as_ptr(), &mut tmp, 8);
-            self.reverse_words(tmp.as_ref(), y);
-            self.alignment_helper
-                .volatile_read_regset(self.ecc.k_mem(0).as_ptr(), &mut tmp, 8);
-            self.reverse_words(tmp.as_ref(), k);
-            } else {
-            self.alignment_helper
-                .volatile_read_regset(self.ecc.qx_mem(0).as_ptr(), &mut tmp, 8);
-            self.reverse_words(tmp.as_ref(), x);
-            self.alignment_helper
-                .volatile_read_regset(self.ecc.qy_mem(0).as_ptr(), &mut tmp, 8);
-            self.reverse_words(tmp.as_ref(), y);
-            self.alignment_helper
-                .volatile_read_regset(self.ecc.qz_mem(0).as_ptr(), &mut tmp, 8);
-            self.reverse_words(tmp.as_ref(), k);
-            }
-        }
+        self.read_jacobian_point_multiplication(k, x, y);
 
         Ok(())
     }
@@ -734,64 +707,382 @@ impl<'d, DM: crate::Mode> Ecc<'d, DM> {
         Ok(())
     }
 
-    /// # Point Addition
+    /// # Jacobian to Affine Conversion
     ///
-    /// In this working mode, ECC first verifies if Point (Px, Py) is on the
-    /// selected elliptic curve or not. If yes, then perform the addition:
-    /// (R_x, R_y) = (J_x, J_y, J_z) = (P_x, P_y, 1) + (Q_x, Q_y, Q_z)
+    /// Converts a point given in Jacobian representation, as produced by the
+    /// Jacobian point multiplication working modes, back to its affine
+    /// representation:
+    /// (x, y) = (J_x / J_z^2, J_y / J_z^3) mod p
     ///
-    /// This functions requires data in Little Endian.
-    /// The affine point representation output is stored in `px` and `py`.
-    /// The Jacobian point representation output is stored in `qx`, `qy`, and
-    /// `qz`.
+    /// On ESP32-C2 the Finite Field Division working mode is used and on
+    /// ESP32-H2 the modular working modes. On the other chips the conversion
+    /// is done in software.
+    ///
+    /// Output is stored in `x` and `y`.
     ///
     /// # Error
     ///
     /// This function will return an error if any bitlength value is different
     /// from the bitlength of the prime fields of the curve.
     ///
-    /// This function will return an error if the point is not on the selected
-    /// elliptic curve.
-    #[cfg(esp32h2)]
-    pub fn affine_point_addition(
+    /// This function will return an error if `jz` is zero, i.e. the point is
+    /// the point at infinity, which has no affine representation.
+    pub fn jacobian_to_affine(
         &mut self,
         curve: &EllipticCurve,
-        px: &mut [u8],
-        py: &mut [u8],
-        qx: &mut [u8],
-        qy: &mut [u8],
-        qz: &mut [u8],
+        jx: &[u8],
+        jy: &[u8],
+        jz: &[u8],
+        x: &mut [u8],
+        y: &mut [u8],
     ) -> Result<(), Error> {
-        let curve = match curve {
-            EllipticCurve::P192 => {
-                if px.len() != 24
-                    || py.len() != 24
-                    || qx.len() != 24
-                    || qy.len() != 24
-                    || qz.len() != 24
-                {
-                    return Err(Error::SizeMismatchCurve);
+        let len = match curve {
+            EllipticCurve::P192 => 24,
+            EllipticCurve::P256 => 32,
+        };
+        if [jx.len(), jy.len(), jz.len(), x.len(), y.len()]
+            .iter()
+            .any(|&l| l != len)
+        {
+            return Err(Error::SizeMismatchCurve);
+        }
+
+        if jz.iter().all(|&b| b == 0) {
+            return Err(Error::PointAtInfinity);
+        }
+
+        cfg_if::cfg_if! {
+            if #[cfg(esp32c2)] {
+                x.copy_from_slice(jx);
+                for _ in 0..2 {
+                    self.finite_field_division(curve, jz, x)?;
                 }
-                false
-            }
-            EllipticCurve::P256 => {
-                if px.len() != 32
-                    || py.len() != 32
-                    || qx.len() != 32
-                    || qy.len() != 32
-                    || qz.len() != 32
-                {
-                    return Err(Error::SizeMismatchCurve);
+
+                y.copy_from_slice(jy);
+                for _ in 0..3 {
+                    self.finite_field_division(curve, jz, y)?;
                 }
-                true
+            } else {
+                use math::U256;
+
+                let p = Modulus::Prime;
+                let z_inv = self.inv_mod(curve, p, &U256::from_be_slice(jz));
+                let z_inv2 = self.mul_mod(curve, p, &z_inv, &z_inv);
+                let z_inv3 = self.mul_mod(curve, p, &z_inv2, &z_inv);
+
+                self.mul_mod(curve, p, &U256::from_be_slice(jx), &z_inv2).write_be_slice(x);
+                self.mul_mod(curve, p, &U256::from_be_slice(jy), &z_inv3).write_be_slice(y);
             }
-        };
-        let mode = WorkMode::PointAdd;
+        }
 
-        let mut tmp = [0_u8; 32];
+        Ok(())
+    }
 
-        tmp[0..px.len()].copy_from_slice(px);
-        self.alignment_helper
+    /// # Elliptic Curve Diffie-Hellman
+    ///
+    /// Computes the shared secret of an ECDH key agreement:
+    /// (S_x, S_y) = private * (P_x, P_y)
+    ///
+    /// The peer's public key (`their_x`, `their_y`) is verified to be on the
+    /// selected curve before the multiplication. The X coordinate of the
+    /// result is the shared secret and is stored in `shared_out`.
+    ///
+    /// # Error
+    ///
+    /// This function will return an error if any bitlength value is different
+    /// from the bitlength of the prime fields of the curve.
+    ///
+    /// This function will return an error if the peer's point is not on the
+    /// selected elliptic curve.
+    ///
+    /// This function will return an error if the result is the point at
+    /// infinity.
+    pub fn ecdh(
+        &mut self,
+        curve: &EllipticCurve,
+        private: &[u8],
+        their_x: &[u8],
+        their_y: &[u8],
+        shared_out: &mut [u8],
+    ) -> Result<(), Error> {
+        let len = match curve {
+            EllipticCurve::P192 => 24,
+            EllipticCurve::P256 => 32,
+        };
+        if [private.len(), their_x.len(), their_y.len(), shared_out.len()]
+            .iter()
+            .any(|&l| l != len)
+        {
+            return Err(Error::SizeMismatchCurve);
+        }
+
+        if private.iter().all(|&b| b == 0) {
+            return Err(Error::PointAtInfinity);
+        }
+
+        let mut x = [0_u8; 32];
+        let mut y = [0_u8; 32];
+        let x = &mut x[..len];
+        let y = &mut y[..len];
+        x.copy_from_slice(their_x);
+        y.copy_from_slice(their_y);
+
+        cfg_if::cfg_if! {
+            if #[cfg(esp32h2)] {
+                let mut qx = [0_u8; 32];
+                let mut qy = [0_u8; 32];
+                let mut qz = [0_u8; 32];
+                self.affine_point_verification_multiplication(
+                    curve,
+                    private,
+                    x,
+                    y,
+                    &mut qx[..len],
+                    &mut qy[..len],
+                    &mut qz[..len],
+                )?;
+            } else {
+                self.affine_point_verification_multiplication(curve, private, x, y)?;
+            }
+        }
+
+        if x.iter().chain(y.iter()).all(|&b| b == 0) {
+            return Err(Error::PointAtInfinity);
+        }
+
+        shared_out.copy_from_slice(x);
+
+        Ok(())
+    }
+
+    /// # ECDSA Signature Verification
+    ///
+    /// Verifies the signature (`r`, `s`) of `hash` against the public key
+    /// (`Q_x`, `Q_y`):
+    /// (R_x, R_y) = (e * s^-1) * G + (r * s^-1) * Q
+    ///
+    /// The signature is valid if R_x mod n equals `r`. The hash is truncated
+    /// to the bitlength of the curve if it is longer.
+    ///
+    /// Both scalar multiplications are done by the point multiplication
+    /// working mode. The final point addition is done in software, as is the
+    /// scalar arithmetic modulo n on chips other than ESP32-H2.
+    ///
+    /// Returns `Ok(false)` if the signature doesn't match.
+    ///
+    /// # Error
+    ///
+    /// This function will return an error if the bitlength of the public key,
+    /// `r` or `s` is different from the bitlength of the prime fields of the
+    /// curve.
+    ///
+    /// This function will return an error if the public key is not on the
+    /// selected elliptic curve.
+    pub fn ecdsa_verify(
+        &mut self,
+        curve: &EllipticCurve,
+        public_key: (&[u8], &[u8]),
+        hash: &[u8],
+        r: &[u8],
+        s: &[u8],
+    ) -> Result<bool, Error> {
+        use math::U256;
+
+        let len = match curve {
+            EllipticCurve::P192 => 24,
+            EllipticCurve::P256 => 32,
+        };
+        let (qx, qy) = public_key;
+        if [qx.len(), qy.len(), r.len(), s.len()]
+            .iter()
+            .any(|&l| l != len)
+        {
+            return Err(Error::SizeMismatchCurve);
+        }
+
+        let n = math::order(curve);
+        let r = U256::from_be_slice(r);
+        let s = U256::from_be_slice(s);
+        if r.is_zero() || s.is_zero() || !r.lt(&n) || !s.lt(&n) {
+            return Ok(false);
+        }
+
+        // use the leftmost bits of the hash if it's longer than the curve
+        let mut e = [0_u8; 32];
+        let hash = &hash[..hash.len().min(len)];
+        e[len - hash.len()..len].copy_from_slice(hash);
+        let e = U256::from_be_slice(&e[..len]).reduce(&n);
+
+        let w = self.inv_mod(curve, Modulus::Order, &s);
+        let u1 = self.mul_mod(curve, Modulus::Order, &e, &w);
+        let u2 = self.mul_mod(curve, Modulus::Order, &r, &w);
+
+        let mut k = [0_u8; 32];
+        let k = &mut k[..len];
+
+        self.affine_point_verification(curve, qx, qy)?;
+
+        // u2 * Q, u2 is never zero since r and s are non-zero
+        let mut x2 = [0_u8; 32];
+        let mut y2 = [0_u8; 32];
+        let x2 = &mut x2[..len];
+        let y2 = &mut y2[..len];
+        x2.copy_from_slice(qx);
+        y2.copy_from_slice(qy);
+        u2.write_be_slice(k);
+        self.affine_point_multiplication(curve, k, x2, y2)?;
+        let p2 = math::AffinePoint::from_be_slices(x2, y2);
+
+        // u1 * G
+        let p1 = if u1.is_zero() {
+            None
+        } else {
+            let (gx, gy) = math::generator(curve);
+            let mut x1 = [0_u8; 32];
+            let mut y1 = [0_u8; 32];
+            let x1 = &mut x1[..len];
+            let y1 = &mut y1[..len];
+            gx.write_be_slice(x1);
+            gy.write_be_slice(y1);
+            u1.write_be_slice(k);
+            self.affine_point_multiplication(curve, k, x1, y1)?;
+            math::AffinePoint::from_be_slices(x1, y1)
+        };
+
+        let p = math::prime(curve);
+        let Some(result) = math::AffinePoint::add(p1, p2, &p) else {
+            return Ok(false);
+        };
+
+        Ok(result.x.reduce(&n) == r)
+    }
+
+    /// # Point Decompression
+    ///
+    /// Recovers the y coordinate of a point given in compressed SEC1 form,
+    /// i.e. a `0x02` or `0x03` prefix followed by the x coordinate, and writes
+    /// it to `y`.
+    ///
+    /// On ESP32-H2 the square root is computed with the modular
+    /// multiplication working mode. The other chips compute it in software
+    /// with a few hundred bit-serial modular multiplications, which can take
+    /// well over 10 ms for P-256. The software path isn't constant-time, its
+    /// duration depends on the x coordinate.
+    ///
+    /// # Error
+    ///
+    /// This function will return an error if the bitlength of `y` or the x
+    /// coordinate is different from the bitlength of the prime fields of the
+    /// curve.
+    ///
+    /// This function will return an error if the prefix is invalid or there is
+    /// no point with the given x coordinate on the selected elliptic curve.
+    pub fn decompress_point(
+        &mut self,
+        curve: &EllipticCurve,
+        compressed: &[u8],
+        y: &mut [u8],
+    ) -> Result<(), Error> {
+        use math::U256;
+
+        let len = match curve {
+            EllipticCurve::P192 => 24,
+            EllipticCurve::P256 => 32,
+        };
+        if compressed.len() != len + 1 || y.len() != len {
+            return Err(Error::SizeMismatchCurve);
+        }
+
+        let odd = match compressed[0] {
+            0x02 => false,
+            0x03 => true,
+            _ => return Err(Error::PointNotOnSelectedCurve),
+        };
+        let x = &compressed[1..];
+
+        let p = math::prime(curve);
+        let px = U256::from_be_slice(x);
+        if !px.lt(&p) {
+            return Err(Error::PointNotOnSelectedCurve);
+        }
+
+        // y^2 = x^3 - 3x + b
+        let three_x = px.add_mod(&px, &p).add_mod(&px, &p);
+        let x2 = self.mul_mod(curve, Modulus::Prime, &px, &px);
+        let rhs = self
+            .mul_mod(curve, Modulus::Prime, &x2, &px)
+            .sub_mod(&three_x, &p)
+            .add_mod(&math::b(curve), &p);
+
+        // p = 3 mod 4 for both curves, so rhs^((p + 1) / 4) is a square root
+        let mut py = self.pow_mod(curve, Modulus::Prime, &rhs, &math::sqrt_exponent(&p));
+        if py.bit(0) != odd {
+            py = U256::ZERO.sub_mod(&py, &p);
+        }
+        py.write_be_slice(y);
+
+        // this fails if there is no square root, i.e. x isn't on the curve
+        self.affine_point_verification(curve, x, y)
+    }
+
+    /// # Point Addition
+    ///
+    /// In this working mode, ECC first verifies if Point (Px, Py) is on the
+    /// selected elliptic curve or not. If yes, then perform the addition:
+    /// (R_x, R_y) = (J_x, J_y, J_z) = (P_x, P_y, 1) + (Q_x, Q_y, Q_z)
+    ///
+    /// This functions requires data in Little Endian.
+    /// The affine point representation output is stored in `px` and `py`.
+    /// The Jacobian point representation output is stored in `qx`, `qy`, and
+    /// `qz`.
+    ///
+    /// # Error
+    ///
+    /// This function will return an error if any bitlength value is different
+    /// from the bitlength of the prime fields of the curve.
+    ///
+    /// This function will return an error if the point is not on the selected
+    /// elliptic curve.
+    #[cfg(esp32h2)]
+    pub fn affine_point_addition(
+        &mut self,
+        curve: &EllipticCurve,
+        px: &mut [u8],
+        py: &mut [u8],
+        qx: &mut [u8],
+        qy: &mut [u8],
+        qz: &mut [u8],
+    ) -> Result<(), Error> {
+        let curve = match curve {
+            EllipticCurve::P192 => {
+                if px.len() != 24
+                    || py.len() != 24
+                    || qx.len() != 24
+                    || qy.len() != 24
+                    || qz.len() != 24
+                {
+                    return Err(Error::SizeMismatchCurve);
+                }
+                false
+            }
+            EllipticCurve::P256 => {
+                if px.len() != 32
+                    || py.len() != 32
+                    || qx.len() != 32
+                    || qy.len() != 32
+                    || qz.len() != 32
+                {
+                    return Err(Error::SizeMismatchCurve);
+                }
+                true
+            }
+        };
+        let mode = WorkMode::PointAdd;
+
+        let mut tmp = [0_u8; 32];
+
+        tmp[0..px.len()].copy_from_slice(px);
+        self.alignment_helper
             .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), &tmp, 8);
         tmp[0..py.len()].copy_from_slice(py);
         self.alignment_helper
@@ -926,10 +1217,273 @@ impl<'d, DM: crate::Mode> Ecc<'d, DM> {
         Ok(())
     }
 
+    /// # Mod Inverse
+    ///
+    /// Computes a^-1 mod N in place by performing a mod division with 1 as
+    /// the numerator.
+    ///
+    /// This functions requires data in Little Endian.
+    /// Output is stored in `a`.
+    ///
+    /// # Error
+    ///
+    /// This function will return an error if the bitlength of `a` is different
+    /// from the bitlength of the prime fields of the curve.
+    #[cfg(esp32h2)]
+    pub fn mod_inverse(&mut self, curve: &EllipticCurve, a: &mut [u8]) -> Result<(), Error> {
+        let mut one = [0_u8; 32];
+        one[0] = 1;
+        let len = a.len().min(one.len());
+
+        self.mod_operations(curve, &mut one[..len], a, WorkMode::ModDiv)
+    }
+
+    fn start_affine_point_multiplication(
+        &mut self,
+        curve: &EllipticCurve,
+        k: &[u8],
+        x: &[u8],
+        y: &[u8],
+    ) -> Result<(), Error> {
+        let curve = key_length(curve, &[k, x, y])?;
+        let mode = WorkMode::PointMultiMode;
+
+        let mut tmp = [0_u8; 32];
+        self.reverse_words(k, &mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.k_mem(0).as_ptr(), tmp.as_ref(), 8);
+        self.reverse_words(x, &mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), tmp.as_ref(), 8);
+        self.reverse_words(y, &mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.py_mem(0).as_ptr(), tmp.as_ref(), 8);
+
+        self.ecc.mult_conf().write(|w| unsafe {
+            w.work_mode()
+                .bits(mode as u8)
+                .key_length()
+                .bit(curve)
+                .start()
+                .set_bit()
+        });
+
+        Ok(())
+    }
+
+    fn read_affine_point_multiplication(&mut self, x: &mut [u8], y: &mut [u8]) {
+        let mut tmp = [0_u8; 32];
+        self.alignment_helper
+            .volatile_read_regset(self.ecc.px_mem(0).as_ptr(), &mut tmp, 8);
+        self.reverse_words(tmp.as_ref(), x);
+        self.alignment_helper
+            .volatile_read_regset(self.ecc.py_mem(0).as_ptr(), &mut tmp, 8);
+        self.reverse_words(tmp.as_ref(), y);
+    }
+
+    fn start_affine_point_verification(
+        &mut self,
+        curve: &EllipticCurve,
+        x: &[u8],
+        y: &[u8],
+    ) -> Result<(), Error> {
+        let curve = key_length(curve, &[x, y])?;
+        let mode = WorkMode::PointVerif;
+
+        let mut tmp = [0_u8; 32];
+        self.reverse_words(x, &mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), tmp.as_ref(), 8);
+        self.reverse_words(y, &mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.py_mem(0).as_ptr(), tmp.as_ref(), 8);
+
+        self.ecc.mult_conf().write(|w| unsafe {
+            w.work_mode()
+                .bits(mode as u8)
+                .key_length()
+                .bit(curve)
+                .start()
+                .set_bit()
+        });
+
+        Ok(())
+    }
+
+    fn check_verification_result(&mut self) -> Result<(), Error> {
+        if !self.ecc.mult_conf().read().verification_result().bit() {
+            self.ecc.mult_conf().reset();
+            return Err(Error::PointNotOnSelectedCurve);
+        }
+
+        Ok(())
+    }
+
+    fn start_jacobian_point_multiplication(
+        &mut self,
+        curve: &EllipticCurve,
+        k: &[u8],
+        x: &[u8],
+        y: &[u8],
+    ) -> Result<(), Error> {
+        let curve = key_length(curve, &[k, x, y])?;
+        let mode = WorkMode::JacobianPointMulti;
+
+        let mut tmp = [0_u8; 32];
+        self.reverse_words(k, &mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.k_mem(0).as_ptr(), tmp.as_ref(), 8);
+        self.reverse_words(x, &mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), tmp.as_ref(), 8);
+        self.reverse_words(y, &mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.py_mem(0).as_ptr(), tmp.as_ref(), 8);
+
+        self.ecc.mult_conf().write(|w| unsafe {
+            w.work_mode()
+                .bits(mode as u8)
+                .key_length()
+                .bit(curve)
+                .start()
+                .set_bit()
+        });
+
+        Ok(())
+    }
+
+    fn read_jacobian_point_multiplication(&mut self, k: &mut [u8], x: &mut [u8], y: &mut [u8]) {
+        let mut tmp = [0_u8; 32];
+        cfg_if::cfg_if! {
+            if #[cfg(not(esp32h2))] {
+            self.alignment_helper
+                .volatile_read_regset(self.ecc.px_mem(0).as_ptr(), &mut tmp, 8);
+            self.reverse_words(tmp.as_ref(), x);
+            self.alignment_helper
+                .volatile_read_regset(self.ecc.py_mem(0).as_ptr(), &mut tmp, 8);
+            self.reverse_words(tmp.as_ref(), y);
+            self.alignment_helper
+                .volatile_read_regset(self.ecc.k_mem(0).as_ptr(), &mut tmp, 8);
+            self.reverse_words(tmp.as_ref(), k);
+            } else {
+            self.alignment_helper
+                .volatile_read_regset(self.ecc.qx_mem(0).as_ptr(), &mut tmp, 8);
+            self.reverse_words(tmp.as_ref(), x);
+            self.alignment_helper
+                .volatile_read_regset(self.ecc.qy_mem(0).as_ptr(), &mut tmp, 8);
+            self.reverse_words(tmp.as_ref(), y);
+            self.alignment_helper
+                .volatile_read_regset(self.ecc.qz_mem(0).as_ptr(), &mut tmp, 8);
+            self.reverse_words(tmp.as_ref(), k);
+            }
+        }
+    }
+
     fn is_busy(&self) -> bool {
         self.ecc.mult_conf().read().start().bit_is_set()
     }
 
+    /// `a * b` modulo the prime or the order of the curve, both operands must
+    /// be reduced.
+    ///
+    /// Uses the modular multiplication working mode on ESP32-H2, the other
+    /// chips do it in software.
+    fn mul_mod(
+        &mut self,
+        curve: &EllipticCurve,
+        modulus: Modulus,
+        a: &math::U256,
+        b: &math::U256,
+    ) -> math::U256 {
+        cfg_if::cfg_if! {
+            if #[cfg(esp32h2)] {
+                self.mod_operation(curve, modulus, WorkMode::ModMulti, a, b)
+            } else {
+                a.mul_mod(b, &modulus.value(curve))
+            }
+        }
+    }
+
+    /// `a ^ exp` modulo the prime or the order of the curve, `a` must be
+    /// reduced.
+    ///
+    /// The multiplications are done by [Ecc::mul_mod].
+    fn pow_mod(
+        &mut self,
+        curve: &EllipticCurve,
+        modulus: Modulus,
+        a: &math::U256,
+        exp: &math::U256,
+    ) -> math::U256 {
+        let mut result = math::U256::ONE;
+        for i in (0..exp.bits()).rev() {
+            result = self.mul_mod(curve, modulus, &result, &result);
+            if exp.bit(i) {
+                result = self.mul_mod(curve, modulus, &result, a);
+            }
+        }
+        result
+    }
+
+    /// `a ^ -1` modulo the prime or the order of the curve, `a` must be
+    /// reduced.
+    ///
+    /// Uses the modular division working mode on ESP32-H2, the other chips do
+    /// it in software.
+    fn inv_mod(&mut self, curve: &EllipticCurve, modulus: Modulus, a: &math::U256) -> math::U256 {
+        cfg_if::cfg_if! {
+            if #[cfg(esp32h2)] {
+                self.mod_operation(curve, modulus, WorkMode::ModDiv, &math::U256::ONE, a)
+            } else {
+                a.inv_mod(&modulus.value(curve))
+            }
+        }
+    }
+
+    /// Run one of the modular working modes, unlike
+    /// [Ecc::mod_operations] this also supports the prime of the curve as the
+    /// modulus.
+    #[cfg(esp32h2)]
+    fn mod_operation(
+        &mut self,
+        curve: &EllipticCurve,
+        modulus: Modulus,
+        mode: WorkMode,
+        a: &math::U256,
+        b: &math::U256,
+    ) -> math::U256 {
+        let key_length = matches!(curve, EllipticCurve::P256);
+        let result = match mode {
+            WorkMode::ModAdd | WorkMode::ModSub => self.ecc.px_mem(0).as_ptr(),
+            _ => self.ecc.py_mem(0).as_ptr(),
+        };
+
+        let mut tmp = [0_u8; 32];
+        a.write_le_slice(&mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), &tmp, 8);
+        b.write_le_slice(&mut tmp);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.py_mem(0).as_ptr(), &tmp, 8);
+
+        self.ecc.mult_conf().write(|w| unsafe {
+            w.work_mode()
+                .bits(mode as u8)
+                .key_length()
+                .bit(key_length)
+                .mod_base()
+                .bit(matches!(modulus, Modulus::Prime))
+                .start()
+                .set_bit()
+        });
+
+        // wait for interrupt
+        while self.is_busy() {}
+
+        self.alignment_helper.volatile_read_regset(result, &mut tmp, 8);
+        math::U256::from_le_slice(&tmp)
+    }
+
     fn reverse_words(&self, src: &[u8], dst: &mut [u8]) {
         let n = core::cmp::min(src.len(), dst.len());
         let nsrc = if src.len() > n {
@@ -947,3 +1501,455 @@ impl<'d, DM: crate::Mode> Ecc<'d, DM> {
         }
     }
 }
+
+impl<'d, DM: crate::Mode> Drop for Ecc<'d, DM> {
+    fn drop(&mut self) {
+        // Don't leave secret scalars and intermediate results in the
+        // peripheral's memory
+        let zeros = [0_u8; 32];
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.k_mem(0).as_ptr(), &zeros, 8);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.px_mem(0).as_ptr(), &zeros, 8);
+        self.alignment_helper
+            .volatile_write_regset(self.ecc.py_mem(0).as_ptr(), &zeros, 8);
+
+        #[cfg(esp32h2)]
+        {
+            self.alignment_helper
+                .volatile_write_regset(self.ecc.qx_mem(0).as_ptr(), &zeros, 8);
+            self.alignment_helper
+                .volatile_write_regset(self.ecc.qy_mem(0).as_ptr(), &zeros, 8);
+            self.alignment_helper
+                .volatile_write_regset(self.ecc.qz_mem(0).as_ptr(), &zeros, 8);
+        }
+    }
+}
+
+/// Check that all parameters have the bitlength of the prime field of the
+/// curve and return the matching `key_length` register value.
+fn key_length(curve: &EllipticCurve, params: &[&[u8]]) -> Result<bool, Error> {
+    let (len, key_length) = match curve {
+        EllipticCurve::P192 => (24, false),
+        EllipticCurve::P256 => (32, true),
+    };
+
+    if params.iter().any(|param| param.len() != len) {
+        return Err(Error::SizeMismatchCurve);
+    }
+
+    Ok(key_length)
+}
+
+/// The modulus of the modular arithmetic done for a curve
+#[derive(Clone, Copy)]
+enum Modulus {
+    /// The prime of the field the curve is defined over
+    Prime,
+    /// The order of the base point of the curve
+    Order,
+}
+
+impl Modulus {
+    #[cfg(not(esp32h2))]
+    fn value(self, curve: &EllipticCurve) -> math::U256 {
+        match self {
+            Modulus::Prime => math::prime(curve),
+            Modulus::Order => math::order(curve),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub(crate) mod asynch {
+    use core::task::Poll;
+
+    use embassy_sync::waitqueue::AtomicWaker;
+    use procmacros::handler;
+
+    static WAKER: AtomicWaker = AtomicWaker::new();
+
+    pub(crate) struct EccFuture<'d> {
+        instance: &'d crate::peripherals::ECC,
+    }
+
+    impl<'d> EccFuture<'d> {
+        // The interrupt is enabled before the operation is started, so the
+        // completion can't be missed
+        pub fn new(instance: &'d crate::peripherals::ECC) -> Self {
+            instance
+                .int_clr()
+                .write(|w| w.calc_done_int_clr().clear_bit_by_one());
+            instance
+                .int_ena()
+                .modify(|_, w| w.calc_done_int_ena().set_bit());
+
+            Self { instance }
+        }
+
+        fn event_bit_is_clear(&self) -> bool {
+            self.instance
+                .int_ena()
+                .read()
+                .calc_done_int_ena()
+                .bit_is_clear()
+        }
+    }
+
+    impl<'d> Drop for EccFuture<'d> {
+        fn drop(&mut self) {
+            self.instance
+                .int_ena()
+                .modify(|_, w| w.calc_done_int_ena().clear_bit());
+        }
+    }
+
+    impl<'d> core::future::Future for EccFuture<'d> {
+        type Output = ();
+
+        fn poll(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Self::Output> {
+            WAKER.register(cx.waker());
+            if self.event_bit_is_clear() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    #[handler]
+    pub(super) fn ecc_interrupt_handler() {
+        let ecc = unsafe { &*crate::peripherals::ECC::ptr() };
+
+        ecc.int_ena()
+            .modify(|_, w| w.calc_done_int_ena().clear_bit());
+        ecc.int_clr()
+            .write(|w| w.calc_done_int_clr().clear_bit_by_one());
+
+        WAKER.wake();
+    }
+}
+
+/// Compare two byte slices in constant time.
+///
+/// The time taken depends only on the length of the inputs, not on their
+/// contents. This is the recommended way to compare secret-dependent outputs
+/// of the driver (e.g. the `r` component of an ECDSA signature) against an
+/// expected value. Slices of different length compare as unequal.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+
+    core::hint::black_box(diff) == 0
+}
+
+/// Software arithmetic used where the hardware doesn't provide the needed
+/// modular operations.
+mod math {
+    use super::EllipticCurve;
+
+    /// Unsigned 256 bit integer, least significant word first.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub(super) struct U256(pub(super) [u32; 8]);
+
+    const P192_P: U256 = U256([
+        0xffffffff, 0xffffffff, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0x00000000,
+        0x00000000,
+    ]);
+
+    const P256_P: U256 = U256([
+        0xffffffff, 0xffffffff, 0xffffffff, 0x00000000, 0x00000000, 0x00000000, 0x00000001,
+        0xffffffff,
+    ]);
+
+    /// The prime of the field the curve is defined over.
+    pub(super) fn prime(curve: &EllipticCurve) -> U256 {
+        match curve {
+            EllipticCurve::P192 => P192_P,
+            EllipticCurve::P256 => P256_P,
+        }
+    }
+
+    const P192_N: U256 = U256([
+        0xb4d22831, 0x146bc9b1, 0x99def836, 0xffffffff, 0xffffffff, 0xffffffff, 0x00000000,
+        0x00000000,
+    ]);
+
+    const P256_N: U256 = U256([
+        0xfc632551, 0xf3b9cac2, 0xa7179e84, 0xbce6faad, 0xffffffff, 0xffffffff, 0x00000000,
+        0xffffffff,
+    ]);
+
+    /// The order of the base point of the curve.
+    pub(super) fn order(curve: &EllipticCurve) -> U256 {
+        match curve {
+            EllipticCurve::P192 => P192_N,
+            EllipticCurve::P256 => P256_N,
+        }
+    }
+
+    const P192_B: U256 = U256([
+        0xc146b9b1, 0xfeb8deec, 0x72243049, 0x0fa7e9ab, 0xe59c80e7, 0x64210519, 0x00000000,
+        0x00000000,
+    ]);
+
+    const P256_B: U256 = U256([
+        0x27d2604b, 0x3bce3c3e, 0xcc53b0f6, 0x651d06b0, 0x769886bc, 0xb3ebbd55, 0xaa3a93e7,
+        0x5ac635d8,
+    ]);
+
+    /// The constant `b` of the curve equation `y^2 = x^3 - 3x + b`.
+    pub(super) fn b(curve: &EllipticCurve) -> U256 {
+        match curve {
+            EllipticCurve::P192 => P192_B,
+            EllipticCurve::P256 => P256_B,
+        }
+    }
+
+    /// The base point of the curve.
+    pub(super) fn generator(curve: &EllipticCurve) -> (U256, U256) {
+        match curve {
+            EllipticCurve::P192 => (
+                U256([
+                    0x82ff1012, 0xf4ff0afd, 0x43a18800, 0x7cbf20eb, 0xb03090f6, 0x188da80e,
+                    0x00000000, 0x00000000,
+                ]),
+                U256([
+                    0x1e794811, 0x73f977a1, 0x6b24cdd5, 0x631011ed, 0xffc8da78, 0x07192b95,
+                    0x00000000, 0x00000000,
+                ]),
+            ),
+            EllipticCurve::P256 => (
+                U256([
+                    0xd898c296, 0xf4a13945, 0x2deb33a0, 0x77037d81, 0x63a440f2, 0xf8bce6e5,
+                    0xe12c4247, 0x6b17d1f2,
+                ]),
+                U256([
+                    0x37bf51f5, 0xcbb64068, 0x6b315ece, 0x2bce3357, 0x7c0f9e16, 0x8ee7eb4a,
+                    0xfe1a7f9b, 0x4fe342e2,
+                ]),
+            ),
+        }
+    }
+
+    /// A point in affine coordinates, `None` is used for the point at
+    /// infinity.
+    #[derive(Clone, Copy)]
+    pub(super) struct AffinePoint {
+        pub(super) x: U256,
+        pub(super) y: U256,
+    }
+
+    impl AffinePoint {
+        /// The hardware reports the point at infinity as (0, 0).
+        pub(super) fn from_be_slices(x: &[u8], y: &[u8]) -> Option<Self> {
+            let x = U256::from_be_slice(x);
+            let y = U256::from_be_slice(y);
+            if x.is_zero() && y.is_zero() {
+                None
+            } else {
+                Some(Self { x, y })
+            }
+        }
+
+        /// Add two points of a curve with `a = -3` over the field of prime `p`.
+        pub(super) fn add(a: Option<Self>, b: Option<Self>, p: &U256) -> Option<Self> {
+            let (a, b) = match (a, b) {
+                (None, b) => return b,
+                (a, None) => return a,
+                (Some(a), Some(b)) => (a, b),
+            };
+
+            let lambda = if a.x == b.x {
+                if a.y != b.y || a.y.is_zero() {
+                    return None;
+                }
+
+                // (3 * x^2 - 3) / (2 * y)
+                let x2 = a.x.mul_mod(&a.x, p).sub_mod(&U256::ONE, p);
+                let num = x2.add_mod(&x2, p).add_mod(&x2, p);
+                num.mul_mod(&a.y.add_mod(&a.y, p).inv_mod(p), p)
+            } else {
+                // (y_b - y_a) / (x_b - x_a)
+                let num = b.y.sub_mod(&a.y, p);
+                num.mul_mod(&b.x.sub_mod(&a.x, p).inv_mod(p), p)
+            };
+
+            let x = lambda.mul_mod(&lambda, p).sub_mod(&a.x, p).sub_mod(&b.x, p);
+            let y = lambda.mul_mod(&a.x.sub_mod(&x, p), p).sub_mod(&a.y, p);
+
+            Some(Self { x, y })
+        }
+    }
+
+    impl U256 {
+        pub(super) const ZERO: Self = Self([0; 8]);
+        pub(super) const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+
+        /// Create a value from a big-endian byte slice of up to 32 bytes whose
+        /// length is a multiple of 4.
+        pub(super) fn from_be_slice(bytes: &[u8]) -> Self {
+            let mut words = [0u32; 8];
+            for (word, chunk) in words.iter_mut().zip(bytes.rchunks_exact(4)) {
+                *word = u32::from_be_bytes(chunk.try_into().unwrap());
+            }
+            Self(words)
+        }
+
+        /// Write the value as big-endian bytes, filling the whole slice.
+        pub(super) fn write_be_slice(&self, bytes: &mut [u8]) {
+            for (word, chunk) in self.0.iter().zip(bytes.rchunks_exact_mut(4)) {
+                chunk.copy_from_slice(&word.to_be_bytes());
+            }
+        }
+
+        /// Create a value from the little-endian bytes used by the modular
+        /// working modes.
+        #[cfg(esp32h2)]
+        pub(super) fn from_le_slice(bytes: &[u8; 32]) -> Self {
+            let mut words = [0u32; 8];
+            for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+                *word = u32::from_le_bytes(chunk.try_into().unwrap());
+            }
+            Self(words)
+        }
+
+        /// Write the value as the little-endian bytes used by the modular
+        /// working modes.
+        #[cfg(esp32h2)]
+        pub(super) fn write_le_slice(&self, bytes: &mut [u8; 32]) {
+            for (word, chunk) in self.0.iter().zip(bytes.chunks_exact_mut(4)) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+            }
+        }
+
+        pub(super) fn is_zero(&self) -> bool {
+            self.0.iter().all(|&w| w == 0)
+        }
+
+        pub(super) fn bit(&self, n: usize) -> bool {
+            (self.0[n / 32] >> (n % 32)) & 1 != 0
+        }
+
+        /// Number of significant bits.
+        pub(super) fn bits(&self) -> usize {
+            for (i, &w) in self.0.iter().enumerate().rev() {
+                if w != 0 {
+                    return i * 32 + (32 - w.leading_zeros() as usize);
+                }
+            }
+            0
+        }
+
+        fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+            let mut result = Self::ZERO;
+            let mut carry = false;
+            for i in 0..8 {
+                let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+                let (sum, c2) = sum.overflowing_add(carry as u32);
+                result.0[i] = sum;
+                carry = c1 || c2;
+            }
+            (result, carry)
+        }
+
+        fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+            let mut result = Self::ZERO;
+            let mut borrow = false;
+            for i in 0..8 {
+                let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+                let (diff, b2) = diff.overflowing_sub(borrow as u32);
+                result.0[i] = diff;
+                borrow = b1 || b2;
+            }
+            (result, borrow)
+        }
+
+        pub(super) fn lt(&self, other: &Self) -> bool {
+            self.overflowing_sub(other).1
+        }
+
+        /// `self mod m` for a value less than `2 * m`.
+        pub(super) fn reduce(&self, m: &Self) -> Self {
+            let (reduced, borrow) = self.overflowing_sub(m);
+            if borrow {
+                *self
+            } else {
+                reduced
+            }
+        }
+
+        /// `self + other mod m`, both operands must be reduced.
+        pub(super) fn add_mod(&self, other: &Self, m: &Self) -> Self {
+            let (sum, carry) = self.overflowing_add(other);
+            let (reduced, borrow) = sum.overflowing_sub(m);
+            if carry || !borrow {
+                reduced
+            } else {
+                sum
+            }
+        }
+
+        /// `self - other mod m`, both operands must be reduced.
+        pub(super) fn sub_mod(&self, other: &Self, m: &Self) -> Self {
+            let (diff, borrow) = self.overflowing_sub(other);
+            if borrow {
+                diff.overflowing_add(m).0
+            } else {
+                diff
+            }
+        }
+
+        /// `self * other mod m`, both operands must be reduced.
+        pub(super) fn mul_mod(&self, other: &Self, m: &Self) -> Self {
+            let mut result = Self::ZERO;
+            for i in (0..other.bits()).rev() {
+                result = result.add_mod(&result, m);
+                if other.bit(i) {
+                    result = result.add_mod(self, m);
+                }
+            }
+            result
+        }
+
+        /// `self ^ exp mod m`, `self` must be reduced.
+        pub(super) fn pow_mod(&self, exp: &Self, m: &Self) -> Self {
+            let mut result = Self::ONE;
+            for i in (0..exp.bits()).rev() {
+                result = result.mul_mod(&result, m);
+                if exp.bit(i) {
+                    result = result.mul_mod(self, m);
+                }
+            }
+            result
+        }
+
+        /// `self ^ -1 mod m` for a prime `m`, `self` must be reduced.
+        ///
+        /// Returns zero if `self` is zero.
+        pub(super) fn inv_mod(&self, m: &Self) -> Self {
+            let exp = m.overflowing_sub(&Self([2, 0, 0, 0, 0, 0, 0, 0])).0;
+            self.pow_mod(&exp, m)
+        }
+    }
+
+    /// The exponent `(p + 1) / 4` which yields a square root modulo a prime
+    /// `p = 3 mod 4`.
+    ///
+    /// The result is only a square root if the base is a quadratic residue,
+    /// which needs to be checked by the caller.
+    pub(super) fn sqrt_exponent(p: &U256) -> U256 {
+        // (p + 1) / 4 == (p >> 2) + 1 for p = 3 mod 4
+        let mut exp = U256::ZERO;
+        for i in 0..8 {
+            exp.0[i] = p.0[i] >> 2 | p.0.get(i + 1).map_or(0, |w| w << 30);
+        }
+        exp.overflowing_add(&U256::ONE).0
+    }
+}
@@ -166,6 +166,10 @@ pub trait RtcPin: Pin {
     fn rtc_set_config(&mut self, input_enable: bool, mux: bool, func: RtcFunction);
 
     /// Enable or disable PAD_HOLD
+    ///
+    /// While the hold is enabled the pad keeps its current configuration and
+    /// output level, also across deep sleep. The hold has to be cleared again
+    /// after waking up to regain control over the pin.
     fn rtcio_pad_hold(&mut self, enable: bool);
 
     /// # Safety
@@ -561,6 +565,23 @@ pub fn connect_high_to_peripheral(signal: InputSignal) {
         });
 }
 
+/// Route a peripheral output signal to the given pin
+///
+/// This configures the GPIO matrix only, the pin still needs to be set up as
+/// an output.
+pub fn connect_peripheral_to_output(signal: OutputSignal, pin: &mut impl OutputPin) {
+    pin.connect_peripheral_to_output(signal, private::Internal);
+}
+
+/// Route the given pin to a peripheral input signal
+///
+/// This configures the GPIO matrix only, the pin still needs to be set up as
+/// an input. Use [connect_low_to_peripheral] or [connect_high_to_peripheral]
+/// to tie the signal to a constant level instead.
+pub fn connect_input_to_peripheral(signal: InputSignal, pin: &mut impl InputPin) {
+    pin.connect_input_to_peripheral(signal, private::Internal);
+}
+
 #[doc(hidden)]
 pub trait PinType {}
 
@@ -1402,7 +1423,7 @@ macro_rules! rtc_pins {
     };
 }
 
-#[cfg(esp32c3)]
+#[cfg(any(esp32c2, esp32c3))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! rtc_pins {
@@ -1410,6 +1431,7 @@ macro_rules! rtc_pins {
         $pin_num:expr
     ) => {
         impl $crate::gpio::RtcPin for GpioPin<$pin_num> {
+            #[cfg(esp32c3)]
             unsafe fn apply_wakeup(&mut self, wakeup: bool, level: u8) {
                 let rtc_cntl = unsafe { &*$crate::peripherals::RTC_CNTL::ptr() };
                 paste::paste! {
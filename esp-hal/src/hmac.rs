@@ -123,6 +123,21 @@ impl<'d> Hmac<'d> {
         self.next_command = NextCommand::None;
     }
 
+    /// Return the peripheral to the state right after [Self::init].
+    ///
+    /// This allows computing several MACs with the same driver instance by
+    /// repeating the [Self::configure], [Self::update], [Self::finalize]
+    /// cycle after each reset.
+    ///
+    /// With [HmacPurpose::ToUser] this must only be called after
+    /// [Self::finalize] returned, since the peripheral only leaves the
+    /// calculation once the result has been read. A MAC which is in progress
+    /// is not aborted by this.
+    pub fn reset(&mut self) {
+        while self.is_busy() {}
+        self.init();
+    }
+
     /// Step 2. Configure HMAC keys and key purposes.
     pub fn configure(&mut self, m: HmacPurpose, key_id: KeyId) -> nb::Result<(), Error> {
         self.hmac
@@ -105,6 +105,40 @@ pub enum Error {
     CommandNrExceeded,
 }
 
+/// I2C slave address
+///
+/// A plain `u8` converts into a 7-bit address, so existing code passing
+/// `0x77` and friends keeps working.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum I2cAddress {
+    /// 7-bit address
+    SevenBit(u8),
+    /// 10-bit address, only the lower 10 bits are used
+    TenBit(u16),
+}
+
+impl I2cAddress {
+    /// Number of bytes needed to transmit the address
+    fn len(&self) -> usize {
+        match self {
+            I2cAddress::SevenBit(_) => 1,
+            I2cAddress::TenBit(_) => 2,
+        }
+    }
+
+    /// First byte of a 10-bit address (`0b11110xx`) without the R/W bit
+    fn ten_bit_header(addr: u16) -> u8 {
+        0b1111_0000 | ((addr >> 7) as u8 & 0b110)
+    }
+}
+
+impl From<u8> for I2cAddress {
+    fn from(addr: u8) -> Self {
+        I2cAddress::SevenBit(addr)
+    }
+}
+
 #[cfg(any(feature = "embedded-hal", feature = "async"))]
 #[derive(PartialEq)]
 // This enum is used to keep track of the last operation that was performed
@@ -324,24 +358,32 @@ where
     T: Instance,
 {
     /// Reads enough bytes from slave with `address` to fill `buffer`
-    pub fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Error> {
-        self.peripheral.master_read(address, buffer)
+    pub fn read(&mut self, address: impl Into<I2cAddress>, buffer: &mut [u8]) -> Result<(), Error> {
+        self.peripheral.master_read(address.into(), buffer)
     }
 
     /// Writes bytes to slave with address `address`
-    pub fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Error> {
-        self.peripheral.master_write(addr, bytes)
+    pub fn write(&mut self, addr: impl Into<I2cAddress>, bytes: &[u8]) -> Result<(), Error> {
+        self.peripheral.master_write(addr.into(), bytes)
     }
 
     /// Writes bytes to slave with address `address` and then reads enough bytes
     /// to fill `buffer` *in a single transaction*
     pub fn write_read(
         &mut self,
-        address: u8,
+        address: impl Into<I2cAddress>,
         bytes: &[u8],
         buffer: &mut [u8],
     ) -> Result<(), Error> {
-        self.peripheral.master_write_read(address, bytes, buffer)
+        self.peripheral.master_write_read(address.into(), bytes, buffer)
+    }
+
+    /// Scans the bus for devices
+    ///
+    /// Probes every non-reserved 7-bit address (`0x08..=0x77`) with an empty
+    /// write and yields the addresses which acknowledged it.
+    pub fn scan(&mut self) -> impl Iterator<Item = u8> + '_ {
+        (0x08..=0x77).filter(move |&address| self.write(address, &[]).is_ok())
     }
 }
 
@@ -353,7 +395,7 @@ where
     type Error = Error;
 
     fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
-        self.peripheral.master_read(address, buffer)
+        self.peripheral.master_read(address.into(), buffer)
     }
 }
 
@@ -365,7 +407,7 @@ where
     type Error = Error;
 
     fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error> {
-        self.peripheral.master_write(addr, bytes)
+        self.peripheral.master_write(addr.into(), bytes)
     }
 }
 
@@ -382,7 +424,7 @@ where
         bytes: &[u8],
         buffer: &mut [u8],
     ) -> Result<(), Self::Error> {
-        self.peripheral.master_write_read(address, bytes, buffer)
+        self.peripheral.master_write_read(address.into(), bytes, buffer)
     }
 }
 
@@ -416,7 +458,7 @@ where
                     // - issue START/RSTART if op is different from previous
                     // - issue STOP if op is the last one
                     self.peripheral.write_operation(
-                        address,
+                        address.into(),
                         bytes,
                         last_op != LastOpWas::Write,
                         op_iter.peek().is_none(),
@@ -429,7 +471,7 @@ where
                     // - issue START/RSTART if op is different from previous
                     // - issue STOP if op is the last one
                     self.peripheral.read_operation(
-                        address,
+                        address.into(),
                         buffer,
                         last_op != LastOpWas::Read,
                         op_iter.peek().is_none(),
@@ -765,7 +807,7 @@ mod asynch {
 
         async fn write_operation<'a, I>(
             &self,
-            address: u8,
+            address: I2cAddress,
             bytes: &[u8],
             start: bool,
             stop: bool,
@@ -796,7 +838,7 @@ mod asynch {
 
         async fn read_operation<'a, I>(
             &self,
-            address: u8,
+            address: I2cAddress,
             buffer: &mut [u8],
             start: bool,
             stop: bool,
@@ -824,7 +866,7 @@ mod asynch {
 
         /// Send data bytes from the `bytes` array to a target slave with the
         /// address `addr`
-        async fn master_write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Error> {
+        async fn master_write(&mut self, addr: I2cAddress, bytes: &[u8]) -> Result<(), Error> {
             // Clear all I2C interrupts
             self.peripheral.clear_all_interrupts();
             self.write_operation(
@@ -841,7 +883,7 @@ mod asynch {
         /// Read bytes from a target slave with the address `addr`
         /// The number of read bytes is deterimed by the size of the `buffer`
         /// argument
-        async fn master_read(&mut self, addr: u8, buffer: &mut [u8]) -> Result<(), Error> {
+        async fn master_read(&mut self, addr: I2cAddress, buffer: &mut [u8]) -> Result<(), Error> {
             // Clear all I2C interrupts
             self.peripheral.clear_all_interrupts();
             self.read_operation(
@@ -859,7 +901,7 @@ mod asynch {
         /// the `buffer` array with n being the size of the array.
         async fn master_write_read(
             &mut self,
-            addr: u8,
+            addr: I2cAddress,
             bytes: &[u8],
             buffer: &mut [u8],
         ) -> Result<(), Error> {
@@ -891,14 +933,22 @@ mod asynch {
         }
 
         /// Writes bytes to slave with address `address`
-        pub async fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Error> {
-            self.master_write(addr, bytes).await?;
+        pub async fn write(
+            &mut self,
+            addr: impl Into<I2cAddress>,
+            bytes: &[u8],
+        ) -> Result<(), Error> {
+            self.master_write(addr.into(), bytes).await?;
             Ok(())
         }
 
         /// Reads enough bytes from slave with `address` to fill `buffer`
-        pub async fn read(&mut self, addr: u8, buffer: &mut [u8]) -> Result<(), Error> {
-            self.master_read(addr, buffer).await?;
+        pub async fn read(
+            &mut self,
+            addr: impl Into<I2cAddress>,
+            buffer: &mut [u8],
+        ) -> Result<(), Error> {
+            self.master_read(addr.into(), buffer).await?;
             Ok(())
         }
 
@@ -906,11 +956,11 @@ mod asynch {
         /// bytes to fill `buffer` *in a single transaction*
         pub async fn write_read(
             &mut self,
-            addr: u8,
+            addr: impl Into<I2cAddress>,
             bytes: &[u8],
             buffer: &mut [u8],
         ) -> Result<(), Error> {
-            self.master_write_read(addr, bytes, buffer).await?;
+            self.master_write_read(addr.into(), bytes, buffer).await?;
             Ok(())
         }
     }
@@ -935,7 +985,7 @@ mod asynch {
                 match op {
                     Operation::Write(bytes) => {
                         self.write_operation(
-                            address,
+                            address.into(),
                             bytes,
                             last_op != LastOpWas::Write,
                             op_iter.peek().is_none(),
@@ -949,7 +999,7 @@ mod asynch {
                         // - issue START/RSTART if op is different from previous
                         // - issue STOP if op is the last one
                         self.read_operation(
-                            address,
+                            address.into(),
                             buffer,
                             last_op != LastOpWas::Read,
                             op_iter.peek().is_none(),
@@ -1427,11 +1477,16 @@ pub trait Instance: crate::private::Sealed {
         }
     }
 
-    fn setup_write<'a, I>(&self, addr: u8, bytes: &[u8], cmd_iterator: &mut I) -> Result<(), Error>
+    fn setup_write<'a, I>(
+        &self,
+        addr: I2cAddress,
+        bytes: &[u8],
+        cmd_iterator: &mut I,
+    ) -> Result<(), Error>
     where
         I: Iterator<Item = &'a COMD>,
     {
-        if bytes.len() > 254 {
+        if bytes.len() + addr.len() > 255 {
             // we could support more by adding multiple write operations
             return Err(Error::ExceedingFifo);
         }
@@ -1442,24 +1497,35 @@ pub trait Instance: crate::private::Sealed {
             Command::Write {
                 ack_exp: Ack::Ack,
                 ack_check_en: true,
-                length: 1 + bytes.len() as u8,
+                length: (addr.len() + bytes.len()) as u8,
             },
         )?;
 
         self.update_config();
 
         // Load address and R/W bit into FIFO
-        write_fifo(
-            self.register_block(),
-            addr << 1 | OperationType::Write as u8,
-        );
+        match addr {
+            I2cAddress::SevenBit(addr) => {
+                write_fifo(
+                    self.register_block(),
+                    addr << 1 | OperationType::Write as u8,
+                );
+            }
+            I2cAddress::TenBit(addr) => {
+                write_fifo(
+                    self.register_block(),
+                    I2cAddress::ten_bit_header(addr) | OperationType::Write as u8,
+                );
+                write_fifo(self.register_block(), addr as u8);
+            }
+        }
 
         Ok(())
     }
 
     fn setup_read<'a, I>(
         &self,
-        addr: u8,
+        addr: I2cAddress,
         buffer: &mut [u8],
         cmd_iterator: &mut I,
     ) -> Result<(), Error>
@@ -1471,6 +1537,20 @@ pub trait Instance: crate::private::Sealed {
             return Err(Error::ExceedingFifo);
         }
 
+        if let I2cAddress::TenBit(_) = addr {
+            // A 10-bit read first writes the full address and then repeats
+            // the header byte with the R/W bit set after a repeated START
+            add_cmd(
+                cmd_iterator,
+                Command::Write {
+                    ack_exp: Ack::Ack,
+                    ack_check_en: true,
+                    length: 2,
+                },
+            )?;
+            add_cmd(cmd_iterator, Command::Start)?;
+        }
+
         // WRITE command
         add_cmd(
             cmd_iterator,
@@ -1504,7 +1584,17 @@ pub trait Instance: crate::private::Sealed {
         self.update_config();
 
         // Load address and R/W bit into FIFO
-        write_fifo(self.register_block(), addr << 1 | OperationType::Read as u8);
+        match addr {
+            I2cAddress::SevenBit(addr) => {
+                write_fifo(self.register_block(), addr << 1 | OperationType::Read as u8);
+            }
+            I2cAddress::TenBit(addr) => {
+                let header = I2cAddress::ten_bit_header(addr);
+                write_fifo(self.register_block(), header | OperationType::Write as u8);
+                write_fifo(self.register_block(), addr as u8);
+                write_fifo(self.register_block(), header | OperationType::Read as u8);
+            }
+        }
 
         Ok(())
     }
@@ -1811,7 +1901,7 @@ pub trait Instance: crate::private::Sealed {
 
     fn write_operation<'a, I>(
         &self,
-        address: u8,
+        address: I2cAddress,
         bytes: &[u8],
         start: bool,
         stop: bool,
@@ -1843,7 +1933,7 @@ pub trait Instance: crate::private::Sealed {
 
     fn read_operation<'a, I>(
         &self,
-        address: u8,
+        address: I2cAddress,
         buffer: &mut [u8],
         start: bool,
         stop: bool,
@@ -1872,7 +1962,7 @@ pub trait Instance: crate::private::Sealed {
 
     /// Send data bytes from the `bytes` array to a target slave with the
     /// address `addr`
-    fn master_write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Error> {
+    fn master_write(&mut self, addr: I2cAddress, bytes: &[u8]) -> Result<(), Error> {
         // Clear all I2C interrupts
         self.clear_all_interrupts();
         self.write_operation(
@@ -1888,7 +1978,7 @@ pub trait Instance: crate::private::Sealed {
     /// Read bytes from a target slave with the address `addr`
     /// The number of read bytes is deterimed by the size of the `buffer`
     /// argument
-    fn master_read(&mut self, addr: u8, buffer: &mut [u8]) -> Result<(), Error> {
+    fn master_read(&mut self, addr: I2cAddress, buffer: &mut [u8]) -> Result<(), Error> {
         // Clear all I2C interrupts
         self.clear_all_interrupts();
         self.read_operation(
@@ -1905,7 +1995,7 @@ pub trait Instance: crate::private::Sealed {
     /// the `buffer` array with n being the size of the array.
     fn master_write_read(
         &mut self,
-        addr: u8,
+        addr: I2cAddress,
         bytes: &[u8],
         buffer: &mut [u8],
     ) -> Result<(), Error> {
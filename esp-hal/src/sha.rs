@@ -141,6 +141,50 @@ impl<'d> Sha<'d, crate::Blocking> {
         sha: impl Peripheral<P = SHA> + 'd,
         mode: ShaMode,
         #[cfg(not(esp32))] interrupt: Option<crate::interrupt::InterruptHandler>,
+    ) -> Self {
+        Self::new_internal(
+            sha,
+            mode,
+            #[cfg(not(esp32))]
+            interrupt,
+        )
+    }
+}
+
+#[cfg(all(feature = "async", not(esp32)))]
+impl<'d> Sha<'d, crate::Async> {
+    /// Create a new instance in [crate::Async] mode.
+    pub fn new_async(sha: impl Peripheral<P = SHA> + 'd, mode: ShaMode) -> Self {
+        Self::new_internal(sha, mode, Some(asynch::sha_interrupt_handler))
+    }
+
+    /// Hashes `data` in a single call and writes the digest to `output`
+    ///
+    /// Like [Self::hash_oneshot], but instead of spinning while a block is
+    /// processed, this awaits the completion interrupt of the peripheral.
+    /// Only the padding blocks appended when finishing the hash are waited
+    /// for by spinning.
+    pub async fn hash_oneshot_async(&mut self, data: &[u8], output: &mut [u8]) {
+        self.sha.irq_ena().write(|w| w.interrupt_ena().set_bit());
+
+        let mut remaining = data;
+        while !remaining.is_empty() {
+            asynch::ShaFuture::new(&self.sha).await;
+            remaining = nb::block!(self.update(remaining)).unwrap();
+        }
+
+        asynch::ShaFuture::new(&self.sha).await;
+        nb::block!(self.finish(output)).unwrap();
+
+        self.sha.irq_ena().write(|w| w.interrupt_ena().clear_bit());
+    }
+}
+
+impl<'d, DM: crate::Mode> Sha<'d, DM> {
+    fn new_internal(
+        sha: impl Peripheral<P = SHA> + 'd,
+        mode: ShaMode,
+        #[cfg(not(esp32))] interrupt: Option<crate::interrupt::InterruptHandler>,
     ) -> Self {
         crate::into_ref!(sha);
 
@@ -442,4 +486,65 @@ impl<'d, DM: crate::Mode> Sha<'d, DM> {
 
         Ok(())
     }
+
+    /// Hashes `data` in a single call and writes the digest to `output`
+    ///
+    /// This is a blocking shorthand for feeding all of `data` through
+    /// [Self::update] followed by [Self::finish]. It must not be mixed with a
+    /// hash calculation which was started by calling [Self::update].
+    pub fn hash_oneshot(&mut self, data: &[u8], output: &mut [u8]) {
+        let mut remaining = data;
+        while !remaining.is_empty() {
+            remaining = nb::block!(self.update(remaining)).unwrap();
+        }
+        nb::block!(self.finish(output)).unwrap();
+    }
+}
+
+#[cfg(all(feature = "async", not(esp32)))]
+pub(crate) mod asynch {
+    use core::task::Poll;
+
+    use embassy_sync::waitqueue::AtomicWaker;
+    use procmacros::handler;
+
+    use crate::peripherals::SHA;
+
+    static WAKER: AtomicWaker = AtomicWaker::new();
+
+    /// Resolves once the peripheral finished processing the current block
+    pub(crate) struct ShaFuture<'a> {
+        sha: &'a SHA,
+    }
+
+    impl<'a> ShaFuture<'a> {
+        pub(crate) fn new(sha: &'a SHA) -> Self {
+            Self { sha }
+        }
+    }
+
+    impl<'a> core::future::Future for ShaFuture<'a> {
+        type Output = ();
+
+        fn poll(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Self::Output> {
+            WAKER.register(cx.waker());
+            if self.sha.busy().read().bits() == 0 {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    #[handler]
+    pub(super) fn sha_interrupt_handler() {
+        unsafe { &*SHA::ptr() }
+            .clear_irq()
+            .write(|w| w.clear_interrupt().set_bit());
+
+        WAKER.wake();
+    }
 }
@@ -87,6 +87,25 @@ impl Efuse {
         unsafe { output.assume_init() }
     }
 
+    /// Read the raw contents of an eFuse block
+    ///
+    /// Copies the block contents, starting at the first byte of the block,
+    /// into `buf`. The size of the blocks differs (at most 32 bytes), bytes
+    /// of `buf` beyond the end of the block are left untouched.
+    ///
+    /// Returns the number of bytes read.
+    pub fn read_block(block: EfuseBlock, buf: &mut [u8]) -> usize {
+        let block_address = block.address();
+        let len = buf.len().min(block.word_count() * 4);
+
+        for (i, chunk) in buf[..len].chunks_mut(4).enumerate() {
+            let word = unsafe { block_address.add(i).read_volatile() };
+            chunk.copy_from_slice(&word.to_le_bytes()[..chunk.len()]);
+        }
+
+        len
+    }
+
     /// Read field value in a big-endian order
     #[inline(always)]
     pub fn read_field_be<T: Sized + 'static>(field: EfuseField) -> T {
@@ -67,6 +67,38 @@ impl Efuse {
         Self::read_field_be(MAC)
     }
 
+    /// Returns the major hardware revision
+    ///
+    /// Chips before v3.0 encode the revision in the `CHIP_VER_REV1` and
+    /// `CHIP_VER_REV2` eFuses together with a bit of the `APB_CTRL` date
+    /// register.
+    pub fn major_chip_version() -> u8 {
+        let apb_ctrl = unsafe { &*crate::peripherals::APB_CTRL::ptr() };
+        let eco_bit0 = Self::read_field_le::<u8>(CHIP_VER_REV1);
+        let eco_bit1 = Self::read_field_le::<u8>(CHIP_VER_REV2);
+        let eco_bit2 = (apb_ctrl.date().read().bits() >> 31) as u8;
+
+        match (eco_bit2 << 2) | (eco_bit1 << 1) | eco_bit0 {
+            1 => 1,
+            3 => 2,
+            7 => 3,
+            _ => 0,
+        }
+    }
+
+    /// Returns the minor hardware revision
+    pub fn minor_chip_version() -> u8 {
+        Self::read_field_le(WAFER_VERSION_MINOR)
+    }
+
+    /// Returns the hardware revision
+    ///
+    /// The chip version is calculated using the following
+    /// formula: MAJOR * 100 + MINOR. (if the result is 1, then version is v0.1)
+    pub fn chip_revision() -> u16 {
+        Self::major_chip_version() as u16 * 100 + Self::minor_chip_version() as u16
+    }
+
     /// Returns the number of CPUs available on the chip.
     ///
     /// While ESP32 chips usually come with two mostly equivalent CPUs (protocol
@@ -122,12 +154,16 @@ impl Efuse {
     }
 }
 
-#[allow(unused)]
+/// An eFuse block
 #[derive(Copy, Clone)]
-pub(crate) enum EfuseBlock {
+pub enum EfuseBlock {
+    /// System configuration and MAC address
     Block0,
+    /// Flash encryption key
     Block1,
+    /// Secure boot key
     Block2,
+    /// User data
     Block3,
 }
 
@@ -142,4 +178,13 @@ impl EfuseBlock {
             Block3 => efuse.blk3_rdata0().as_ptr(),
         }
     }
+
+    /// Number of 32-bit words in this block
+    pub(crate) fn word_count(self) -> usize {
+        use EfuseBlock::*;
+        match self {
+            Block0 => 7,
+            Block1 | Block2 | Block3 => 8,
+        }
+    }
 }
@@ -55,6 +55,24 @@ impl Efuse {
         Self::read_field_be(MAC)
     }
 
+    /// Returns the major hardware revision
+    pub fn major_chip_version() -> u8 {
+        Self::read_field_le(WAFER_VERSION_MAJOR)
+    }
+
+    /// Returns the minor hardware revision
+    pub fn minor_chip_version() -> u8 {
+        Self::read_field_le(WAFER_VERSION_MINOR)
+    }
+
+    /// Returns the hardware revision
+    ///
+    /// The chip version is calculated using the following
+    /// formula: MAJOR * 100 + MINOR. (if the result is 1, then version is v0.1)
+    pub fn chip_revision() -> u16 {
+        Self::major_chip_version() as u16 * 100 + Self::minor_chip_version() as u16
+    }
+
     /// Get status of SPI boot encryption.
     pub fn get_flash_encryption() -> bool {
         (Self::read_field_le::<u8>(SPI_BOOT_CRYPT_CNT).count_ones() % 2) != 0
@@ -162,11 +180,16 @@ impl Efuse {
     }
 }
 
+/// An eFuse block
 #[derive(Copy, Clone)]
-pub(crate) enum EfuseBlock {
+pub enum EfuseBlock {
+    /// System configuration
     Block0,
+    /// System data
     Block1,
+    /// System data, e.g. the MAC address
     Block2,
+    /// Key or user data
     Block3,
 }
 
@@ -181,4 +204,14 @@ impl EfuseBlock {
             Block3 => efuse.rd_blk3_data0().as_ptr(),
         }
     }
+
+    /// Number of 32-bit words in this block
+    pub(crate) fn word_count(self) -> usize {
+        use EfuseBlock::*;
+        match self {
+            Block0 => 2,
+            Block1 => 3,
+            Block2 | Block3 => 8,
+        }
+    }
 }
@@ -55,6 +55,25 @@ impl Efuse {
         Self::read_field_be(MAC)
     }
 
+    /// Returns the major hardware revision
+    pub fn major_chip_version() -> u8 {
+        Self::read_field_le(WAFER_VERSION_MAJOR)
+    }
+
+    /// Returns the minor hardware revision
+    pub fn minor_chip_version() -> u8 {
+        (Self::read_field_le::<u8>(WAFER_VERSION_MINOR_HI) << 3)
+            | Self::read_field_le::<u8>(WAFER_VERSION_MINOR_LO)
+    }
+
+    /// Returns the hardware revision
+    ///
+    /// The chip version is calculated using the following
+    /// formula: MAJOR * 100 + MINOR. (if the result is 1, then version is v0.1)
+    pub fn chip_revision() -> u16 {
+        Self::major_chip_version() as u16 * 100 + Self::minor_chip_version() as u16
+    }
+
     /// Get status of SPI boot encryption.
     pub fn get_flash_encryption() -> bool {
         (Self::read_field_le::<u8>(SPI_BOOT_CRYPT_CNT).count_ones() % 2) != 0
@@ -151,18 +170,30 @@ impl Efuse {
     }
 }
 
+/// An eFuse block
 #[derive(Copy, Clone)]
-pub(crate) enum EfuseBlock {
+pub enum EfuseBlock {
+    /// System configuration
     Block0,
+    /// MAC address and system data
     Block1,
+    /// System data
     Block2,
+    /// User data
     Block3,
+    /// Key 0
     Block4,
+    /// Key 1
     Block5,
+    /// Key 2
     Block6,
+    /// Key 3
     Block7,
+    /// Key 4
     Block8,
+    /// Key 5
     Block9,
+    /// System data
     Block10,
 }
 
@@ -184,4 +215,13 @@ impl EfuseBlock {
             Block10 => efuse.rd_sys_part2_data0().as_ptr(),
         }
     }
+
+    /// Number of 32-bit words in this block
+    pub(crate) fn word_count(self) -> usize {
+        use EfuseBlock::*;
+        match self {
+            Block0 | Block1 => 6,
+            _ => 8,
+        }
+    }
 }
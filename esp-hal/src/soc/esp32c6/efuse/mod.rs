@@ -168,18 +168,30 @@ impl Efuse {
     }
 }
 
+/// An eFuse block
 #[derive(Copy, Clone)]
-pub(crate) enum EfuseBlock {
+pub enum EfuseBlock {
+    /// System configuration
     Block0,
+    /// MAC address and system data
     Block1,
+    /// System data
     Block2,
+    /// User data
     Block3,
+    /// Key 0
     Block4,
+    /// Key 1
     Block5,
+    /// Key 2
     Block6,
+    /// Key 3
     Block7,
+    /// Key 4
     Block8,
+    /// Key 5
     Block9,
+    /// System data
     Block10,
 }
 
@@ -201,4 +213,13 @@ impl EfuseBlock {
             Block10 => efuse.rd_sys_part2_data0().as_ptr(),
         }
     }
+
+    /// Number of 32-bit words in this block
+    pub(crate) fn word_count(self) -> usize {
+        use EfuseBlock::*;
+        match self {
+            Block0 | Block1 => 6,
+            _ => 8,
+        }
+    }
 }
@@ -128,6 +128,8 @@ pub struct SoftwareInterrupt<const NUM: u8>;
 
 impl<const NUM: u8> SoftwareInterrupt<NUM> {
     /// Sets the interrupt handler for this software-interrupt
+    ///
+    /// The interrupt is enabled on the current core.
     pub fn set_interrupt_handler(&mut self, handler: InterruptHandler) {
         let interrupt = match NUM {
             0 => crate::peripherals::Interrupt::FROM_CPU_INTR0,
@@ -144,6 +146,11 @@ impl<const NUM: u8> SoftwareInterrupt<NUM> {
     }
 
     /// Trigger this software-interrupt
+    ///
+    /// The software-interrupt registers are shared by all cores. The
+    /// interrupt is handled by the core(s) it's enabled on, e.g. the core
+    /// which called [Self::set_interrupt_handler]. To signal another core, set
+    /// the interrupt handler from that core.
     pub fn raise(&self) {
         #[cfg(not(any(esp32c6, esp32h2)))]
         let system = unsafe { &*SYSTEM::PTR };
@@ -207,6 +214,38 @@ impl<const NUM: u8> SoftwareInterrupt<NUM> {
         }
     }
 
+    /// Check if this software-interrupt is raised
+    pub fn is_pending(&self) -> bool {
+        #[cfg(not(any(esp32c6, esp32h2)))]
+        let system = unsafe { &*SYSTEM::PTR };
+        #[cfg(any(esp32c6, esp32h2))]
+        let system = unsafe { &*crate::peripherals::INTPRI::PTR };
+
+        match NUM {
+            0 => system
+                .cpu_intr_from_cpu_0()
+                .read()
+                .cpu_intr_from_cpu_0()
+                .bit_is_set(),
+            1 => system
+                .cpu_intr_from_cpu_1()
+                .read()
+                .cpu_intr_from_cpu_1()
+                .bit_is_set(),
+            2 => system
+                .cpu_intr_from_cpu_2()
+                .read()
+                .cpu_intr_from_cpu_2()
+                .bit_is_set(),
+            3 => system
+                .cpu_intr_from_cpu_3()
+                .read()
+                .cpu_intr_from_cpu_3()
+                .bit_is_set(),
+            _ => unreachable!(),
+        }
+    }
+
     /// Unsafely create an instance of this peripheral out of thin air.
     ///
     /// # Safety
@@ -686,6 +725,28 @@ impl PeripheralClockControl {
             }
         });
     }
+
+    /// Gates or ungates the clock of the given timer group, keeping its
+    /// state
+    pub(crate) fn set_timer_group_clock_enabled(timer_group: u8, enabled: bool) {
+        let system = unsafe { &*SYSTEM::PTR };
+
+        #[cfg(esp32)]
+        let perip_clk_en0 = { &system.perip_clk_en() };
+        #[cfg(not(esp32))]
+        let perip_clk_en0 = { &system.perip_clk_en0() };
+
+        critical_section::with(|_cs| match timer_group {
+            0 => {
+                perip_clk_en0.modify(|_, w| w.timergroup_clk_en().bit(enabled));
+            }
+            #[cfg(timg1)]
+            1 => {
+                perip_clk_en0.modify(|_, w| w.timergroup1_clk_en().bit(enabled));
+            }
+            _ => unreachable!(),
+        });
+    }
 }
 
 #[cfg(any(esp32c6, esp32h2))]
@@ -1096,6 +1157,27 @@ impl PeripheralClockControl {
             }
         }
     }
+
+    /// Gates or ungates the clock of the given timer group, keeping its
+    /// state
+    pub(crate) fn set_timer_group_clock_enabled(timer_group: u8, enabled: bool) {
+        let system = unsafe { &*SYSTEM::PTR };
+
+        match timer_group {
+            0 => {
+                system
+                    .timergroup0_timer_clk_conf()
+                    .modify(|_, w| w.tg0_timer_clk_en().bit(enabled));
+            }
+            #[cfg(timg1)]
+            1 => {
+                system
+                    .timergroup1_timer_clk_conf()
+                    .modify(|_, w| w.tg1_timer_clk_en().bit(enabled));
+            }
+            _ => unreachable!(),
+        }
+    }
 }
 
 /// Controls the configuration of the chip's clocks.
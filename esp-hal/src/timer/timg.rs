@@ -64,6 +64,7 @@ use core::{
 };
 
 use fugit::{HertzU32, Instant, MicrosDurationU64};
+use portable_atomic::{AtomicBool, Ordering};
 
 use super::Error;
 #[cfg(timg1)]
@@ -82,6 +83,13 @@ use crate::{
     Mode,
 };
 
+/// Whether the clock of a timer group is gated by [`TimerGroup::suspend`]
+static SUSPENDED: [AtomicBool; 2] = [AtomicBool::new(false), AtomicBool::new(false)];
+
+fn is_suspended(timer_group: u8) -> bool {
+    SUSPENDED[timer_group as usize].load(Ordering::Acquire)
+}
+
 /// Interrupts which can be registered in [Blocking] mode
 #[derive(Debug, Default)]
 pub struct TimerInterrupts {
@@ -356,6 +364,116 @@ where
     }
 }
 
+impl<'d, T, DM> TimerGroup<'d, T, DM>
+where
+    T: TimerGroupInstance,
+    DM: Mode,
+{
+    /// Gate the clock of the timer group to save power.
+    ///
+    /// While suspended the timers of this group do not count and their alarms
+    /// will not fire. The counter values and the alarm configuration are
+    /// retained, so the timers continue counting from where they stopped once
+    /// [`TimerGroup::resume`] is called.
+    ///
+    /// On chips where the watchdog timer shares the clock of the timer group
+    /// it is stopped as well.
+    ///
+    /// While the group is suspended, `now()` returns the counter value the
+    /// timer stopped at and `wait()` / `wait_async()` return immediately.
+    pub fn suspend(&mut self) {
+        if is_suspended(T::id()) {
+            return;
+        }
+
+        // Latch the counters while the clock is still running, `now()` returns
+        // the latched value while suspended
+        self.timer0.timg.now();
+        #[cfg(timg_timer1)]
+        self.timer1.timg.now();
+
+        SUSPENDED[T::id() as usize].store(true, Ordering::Release);
+        PeripheralClockControl::set_timer_group_clock_enabled(T::id(), false);
+    }
+
+    /// Re-enable the clock of a timer group previously gated by
+    /// [`TimerGroup::suspend`].
+    pub fn resume(&mut self) {
+        PeripheralClockControl::set_timer_group_clock_enabled(T::id(), true);
+        SUSPENDED[T::id() as usize].store(false, Ordering::Release);
+    }
+
+    /// Clear the interrupt status of all timers in this group at once.
+    ///
+    /// The hardware disables the alarm of a timer when it fires, so the
+    /// alarms of running periodic (auto-reloading) timers whose interrupt was
+    /// pending are re-armed.
+    pub fn clear_all_interrupts(&self) {
+        let reg_block = self.timer0.register_block();
+
+        let pending = reg_block.int_raw_timers().read();
+
+        reg_block.int_clr_timers().write(|w| {
+            #[cfg(timg_timer1)]
+            w.t(1).clear_bit_by_one();
+            w.t(0).clear_bit_by_one()
+        });
+
+        let rearm = |timer: u8| {
+            if pending.t(timer).bit_is_clear() {
+                return;
+            }
+
+            let t = reg_block.t(timer.into());
+            let config = t.config().read();
+            if config.autoreload().bit_is_set() && config.en().bit_is_set() {
+                t.config().modify(|_, w| w.alarm_en().set_bit());
+            }
+        };
+
+        rearm(0);
+        #[cfg(timg_timer1)]
+        rearm(1);
+    }
+}
+
+/// Clock source of a timer, see [`Timer::set_clock_source`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TimerClockSource {
+    /// APB clock (PLL_F80M_CLK on ESP32-C6)
+    #[cfg(not(esp32h2))]
+    Apb,
+    /// PLL_F48M_CLK
+    #[cfg(esp32h2)]
+    Pll48M,
+    /// Crystal clock
+    #[cfg(not(esp32))]
+    Xtal,
+}
+
+/// Snapshot of the configuration of a timer, see [`Timer::config_snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimerConfig {
+    /// Frequency of the timer's clock source, in Hz
+    pub clock_source_hz: u32,
+    /// Divider applied to the clock source
+    pub divider: u32,
+    /// Whether the counter is reloaded when the alarm fires
+    pub auto_reload: bool,
+    /// Whether the counter is enabled
+    pub counter_active: bool,
+    /// Whether the counter is counting down
+    pub decrementing: bool,
+    /// Whether the alarm is enabled
+    pub alarm_active: bool,
+    /// Alarm value, in ticks
+    pub alarm_value: u64,
+    /// Whether the interrupt of the timer is enabled
+    pub interrupt_enabled: bool,
+}
+
 /// General-purpose timer.
 pub struct Timer<T, DM>
 where
@@ -400,9 +518,184 @@ where
     }
 
     /// Block until the timer has elasped.
+    ///
+    /// Returns immediately if the timer group is suspended, see
+    /// [`TimerGroup::suspend`].
     pub fn wait(&mut self) {
+        if is_suspended(self.timg.timer_group()) {
+            return;
+        }
+
         while !self.has_elapsed() {}
     }
+
+    /// Start the timer with an alarm firing every `period`
+    ///
+    /// This resets the counter, enables auto-reload and the interrupt and
+    /// starts the timer. Poll [super::Timer::is_interrupt_set] and clear the
+    /// interrupt with [Self::has_elapsed] or an interrupt handler.
+    pub fn start_periodic(&mut self, period: MicrosDurationU64) -> Result<(), Error> {
+        self.start_alarm(period, true)
+    }
+
+    /// Start the timer with an alarm firing once after `after`
+    ///
+    /// This resets the counter, disables auto-reload, enables the interrupt
+    /// and starts the timer.
+    pub fn start_oneshot(&mut self, after: MicrosDurationU64) -> Result<(), Error> {
+        self.start_alarm(after, false)
+    }
+
+    fn start_alarm(&mut self, timeout: MicrosDurationU64, auto_reload: bool) -> Result<(), Error> {
+        use super::Timer as _;
+
+        self.stop();
+        self.clear_interrupt();
+        self.reset();
+
+        self.enable_auto_reload(auto_reload);
+        self.load_value(timeout)?;
+        self.enable_interrupt(true);
+        self.start();
+
+        Ok(())
+    }
+
+    /// Start the timer counting down from the given value.
+    ///
+    /// The counter is loaded with `from` and decremented until it reaches
+    /// zero, at which point the alarm fires. While counting down, `now()`
+    /// returns the remaining time rather than the elapsed time.
+    ///
+    /// Calling `start()` switches the timer back to counting up.
+    pub fn start_countdown(&self, from: MicrosDurationU64) -> Result<(), Error> {
+        let ticks = timeout_to_ticks(from, self.apb_clk_freq, self.timg.divider());
+
+        // The counter is 54-bits wide, so we must ensure that the provided
+        // value is not too wide:
+        if (ticks & !0x3F_FFFF_FFFF_FFFF) != 0 {
+            return Err(Error::InvalidTimeout);
+        }
+
+        self.timg.set_counter_active(false);
+        self.timg.set_alarm_active(false);
+        self.timg.clear_interrupt();
+
+        self.timg.load_counter(ticks);
+        self.timg.load_alarm_value(0);
+        self.timg.set_counter_decrementing(true);
+
+        self.timg.set_counter_active(true);
+        self.timg.set_alarm_active(true);
+
+        Ok(())
+    }
+
+    /// Read the current value of the counter, in ticks.
+    ///
+    /// Unlike `now()` the value isn't converted to microseconds, so no
+    /// precision is lost. See [`Timer::tick_rate`] to convert it.
+    pub fn now_ticks(&self) -> u64 {
+        self.timg.now()
+    }
+
+    /// The frequency the counter is incremented with, i.e. the frequency of
+    /// the clock source divided by the divider.
+    pub fn tick_rate(&self) -> HertzU32 {
+        self.apb_clk_freq / self.timg.divider()
+    }
+
+    /// Convert a number of ticks of this timer to a duration.
+    pub fn ticks_to_duration(&self, ticks: u64) -> MicrosDurationU64 {
+        MicrosDurationU64::micros(ticks_to_timeout(
+            ticks,
+            self.apb_clk_freq,
+            self.timg.divider(),
+        ))
+    }
+
+    /// Convert a duration to a number of ticks of this timer.
+    pub fn duration_to_ticks(&self, duration: MicrosDurationU64) -> u64 {
+        timeout_to_ticks(duration, self.apb_clk_freq, self.timg.divider())
+    }
+
+    /// Read the counter value, in ticks, captured by the last `cnt_cap` ETM
+    /// task.
+    ///
+    /// The capture task latches the counter into the same registers `now()`
+    /// reads, so this returns the value of the last capture or the last call
+    /// to `now()`, whichever happened last.
+    ///
+    /// To time-stamp an external event, connect a GPIO ETM event (e.g. a
+    /// rising edge) to [`etm::TimerEtmTasks::cnt_cap`] via an ETM channel
+    /// and read the timestamp with this function afterwards.
+    #[cfg(soc_etm)]
+    pub fn last_captured(&self) -> u64 {
+        self.timg.read_latched()
+    }
+
+    /// Change the clock source of the timer.
+    ///
+    /// The frequency used to convert between ticks and durations is updated
+    /// accordingly, the divider is kept. Using the crystal clock keeps the
+    /// timer counting at a stable rate while the PLL is powered down, e.g.
+    /// during light sleep.
+    ///
+    /// On ESP32-C6 and ESP32-H2 the clock source is shared by all timers of
+    /// the timer group.
+    pub fn set_clock_source(&mut self, source: TimerClockSource, clocks: &Clocks) {
+        self.timg.set_clock_source(source);
+
+        self.apb_clk_freq = match source {
+            #[cfg(not(esp32h2))]
+            TimerClockSource::Apb => clocks.apb_clock,
+            #[cfg(esp32h2)]
+            TimerClockSource::Pll48M => clocks.pll_48m_clock,
+            #[cfg(not(esp32))]
+            TimerClockSource::Xtal => clocks.xtal_clock,
+        };
+    }
+
+    /// Read the current configuration of the timer from its registers.
+    ///
+    /// This is mainly useful for debugging.
+    pub fn config_snapshot(&self) -> TimerConfig {
+        let reg_block = self.register_block();
+        let t = reg_block.t(self.timer_number().into());
+        let config = t.config().read();
+
+        let alarm_lo = t.alarmlo().read().alarm_lo().bits() as u64;
+        let alarm_hi = t.alarmhi().read().alarm_hi().bits() as u64;
+
+        TimerConfig {
+            clock_source_hz: self.apb_clk_freq.raw(),
+            divider: self.timg.divider(),
+            auto_reload: config.autoreload().bit_is_set(),
+            counter_active: config.en().bit_is_set(),
+            decrementing: config.increase().bit_is_clear(),
+            alarm_active: config.alarm_en().bit_is_set(),
+            alarm_value: (alarm_hi << 32) | alarm_lo,
+            interrupt_enabled: reg_block
+                .int_ena_timers()
+                .read()
+                .t(self.timer_number())
+                .bit_is_set(),
+        }
+    }
+
+    /// Read the timestamp latched by the last counter update, without
+    /// triggering a new update.
+    ///
+    /// Unlike `now()` this does not write to the peripheral and does not wait
+    /// for the update handshake, which makes it cheaper to call in hot loops.
+    /// The returned value is only as recent as the last call to `now()` (on
+    /// any handle of this timer), so it may be arbitrarily stale.
+    pub fn now_cached(&self) -> Instant<u64, 1, 1_000_000> {
+        let ticks = self.timg.read_latched();
+        let micros = ticks_to_timeout(ticks, self.apb_clk_freq, self.timg.divider());
+
+        Instant::<u64, 1, 1_000_000>::from_ticks(micros)
+    }
 }
 
 impl<T, DM> Deref for Timer<T, DM>
@@ -442,6 +735,7 @@ where
     fn start(&self) {
         self.timg.set_counter_active(false);
         self.timg.set_alarm_active(false);
+        self.timg.clear_interrupt();
 
         self.timg.reset_counter();
         self.timg.set_counter_decrementing(false);
@@ -473,17 +767,7 @@ where
     }
 
     fn now(&self) -> Instant<u64, 1, 1_000_000> {
-        let t = self.register_block().t(self.timer_number().into());
-
-        t.update().write(|w| w.update().set_bit());
-        while t.update().read().update().bit_is_set() {
-            // Wait for the update to complete
-        }
-
-        let value_lo = t.lo().read().bits() as u64;
-        let value_hi = t.hi().read().bits() as u64;
-
-        let ticks = (value_hi << 32) | value_lo;
+        let ticks = self.now_ticks();
         let micros = ticks_to_timeout(ticks, self.apb_clk_freq, self.timg.divider());
 
         Instant::<u64, 1, 1_000_000>::from_ticks(micros)
@@ -548,10 +832,16 @@ where
 pub trait Instance: Sealed + Enable {
     fn register_block(&self) -> &RegisterBlock;
 
+    fn timer_group(&self) -> u8;
+
     fn timer_number(&self) -> u8;
 
+    fn set_clock_source(&self, source: TimerClockSource);
+
     fn reset_counter(&self);
 
+    fn load_counter(&self, value: u64);
+
     fn set_counter_active(&self, state: bool);
 
     fn is_counter_active(&self) -> bool;
@@ -574,6 +864,8 @@ pub trait Instance: Sealed + Enable {
 
     fn now(&self) -> u64;
 
+    fn read_latched(&self) -> u64;
+
     fn divider(&self) -> u32;
 
     fn set_divider(&self, divider: u16);
@@ -632,15 +924,61 @@ where
         unsafe { &*TG::register_block() }
     }
 
+    fn timer_group(&self) -> u8 {
+        TG::id()
+    }
+
     fn timer_number(&self) -> u8 {
         T
     }
 
+    fn set_clock_source(&self, source: TimerClockSource) {
+        cfg_if::cfg_if! {
+            if #[cfg(any(esp32c6, esp32h2))] {
+                // 0: XTAL_CLK, 1: PLL_F80M_CLK (ESP32-C6), 2: PLL_F48M_CLK (ESP32-H2)
+                let sel = match source {
+                    TimerClockSource::Xtal => 0,
+                    #[cfg(esp32c6)]
+                    TimerClockSource::Apb => 1,
+                    #[cfg(esp32h2)]
+                    TimerClockSource::Pll48M => 2,
+                };
+
+                let pcr = unsafe { &*crate::peripherals::PCR::PTR };
+                match TG::id() {
+                    0 => {
+                        pcr.timergroup0_timer_clk_conf()
+                            .modify(|_, w| unsafe { w.tg0_timer_clk_sel().bits(sel) });
+                    }
+                    _ => {
+                        pcr.timergroup1_timer_clk_conf()
+                            .modify(|_, w| unsafe { w.tg1_timer_clk_sel().bits(sel) });
+                    }
+                }
+            } else if #[cfg(esp32)] {
+                // ESP32 has only APB clock source, do nothing
+                let _ = source;
+            } else {
+                unsafe { Self::t() }
+                    .config()
+                    .modify(|_, w| w.use_xtal().bit(source == TimerClockSource::Xtal));
+            }
+        }
+    }
+
     fn reset_counter(&self) {
+        self.load_counter(0);
+    }
+
+    fn load_counter(&self, value: u64) {
+        let value = value & 0x3F_FFFF_FFFF_FFFF;
+        let high = (value >> 32) as u32;
+        let low = (value & 0xFFFF_FFFF) as u32;
+
         let t = unsafe { Self::t() };
 
-        t.loadlo().write(|w| unsafe { w.load_lo().bits(0) });
-        t.loadhi().write(|w| unsafe { w.load_hi().bits(0) });
+        t.loadlo().write(|w| unsafe { w.load_lo().bits(low) });
+        t.loadhi().write(|w| unsafe { w.load_hi().bits(high) });
 
         t.load().write(|w| unsafe { w.load().bits(1) });
     }
@@ -714,11 +1052,22 @@ where
     }
 
     fn now(&self) -> u64 {
+        // The update handshake never completes while the clock is gated
+        if is_suspended(TG::id()) {
+            return self.read_latched();
+        }
+
         let t = unsafe { Self::t() };
 
         t.update().write(|w| w.update().set_bit());
         while t.update().read().update().bit_is_set() {}
 
+        self.read_latched()
+    }
+
+    fn read_latched(&self) -> u64 {
+        let t = unsafe { Self::t() };
+
         let value_lo = t.lo().read().bits() as u64;
         let value_hi = (t.hi().read().bits() as u64) << 32;
 
@@ -857,8 +1206,41 @@ where
 {
 }
 
+/// Stage of the watchdog timer
+///
+/// Each stage has its own timeout and action. When a stage times out, its
+/// action is taken and the next stage starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MwdtStage {
+    /// The first stage
+    Stage0,
+    /// The second stage
+    Stage1,
+    /// The third stage
+    Stage2,
+    /// The fourth stage
+    Stage3,
+}
+
+/// Action taken when a watchdog stage times out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MwdtStageAction {
+    /// No action, the stage is disabled
+    Off         = 0,
+    /// Trigger the watchdog interrupt
+    Interrupt   = 1,
+    /// Reset the CPU core
+    ResetCpu    = 2,
+    /// Reset the main system, including the CPU cores
+    ResetSystem = 3,
+}
+
 /// Watchdog timer
 pub struct Wdt<TG, DM> {
+    cpu_reset_length: u8,
+    sys_reset_length: u8,
     phantom: PhantomData<(TG, DM)>,
 }
 
@@ -876,6 +1258,8 @@ where
         TG::configure_wdt_src_clk();
 
         Self {
+            cpu_reset_length: 1,
+            sys_reset_length: 1,
             phantom: PhantomData,
         }
     }
@@ -911,7 +1295,7 @@ where
         if !enabled {
             reg_block.wdtconfig0().write(|w| unsafe { w.bits(0) });
         } else {
-            reg_block.wdtconfig0().write(|w| w.wdt_en().bit(true));
+            reg_block.wdtconfig0().modify(|_, w| w.wdt_en().bit(true));
         }
 
         reg_block
@@ -934,7 +1318,53 @@ where
             .write(|w| unsafe { w.wdt_wkey().bits(0u32) });
     }
 
+    /// Set the length of the CPU and system reset pulses issued by the
+    /// watchdog timer
+    ///
+    /// Both values are 3-bit register values selecting the pulse width, as
+    /// described in the Technical Reference Manual. Both default to `1`. The
+    /// lengths are kept when (re-)enabling the watchdog or changing its
+    /// timeout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either value does not fit into 3 bits.
+    pub fn set_reset_lengths(&mut self, cpu: u8, sys: u8) {
+        assert!(cpu < 8 && sys < 8, "Reset lengths must fit into 3 bits");
+
+        self.cpu_reset_length = cpu;
+        self.sys_reset_length = sys;
+
+        let reg_block = unsafe { &*TG::register_block() };
+
+        reg_block
+            .wdtwprotect()
+            .write(|w| unsafe { w.wdt_wkey().bits(0x50D8_3AA1u32) });
+
+        #[cfg_attr(esp32, allow(unused_unsafe))]
+        reg_block.wdtconfig0().modify(|_, w| unsafe {
+            w.wdt_cpu_reset_length()
+                .bits(cpu)
+                .wdt_sys_reset_length()
+                .bits(sys)
+        });
+
+        #[cfg(any(esp32c2, esp32c3, esp32c6))]
+        reg_block
+            .wdtconfig0()
+            .modify(|_, w| w.wdt_conf_update_en().set_bit());
+
+        reg_block
+            .wdtwprotect()
+            .write(|w| unsafe { w.wdt_wkey().bits(0u32) });
+    }
+
     /// Set the timeout, in microseconds, of the watchdog timer
+    ///
+    /// This configures stage 0 to reset the system after `timeout` and turns
+    /// off the other stages, overriding anything configured via
+    /// [Self::set_stage_action]. Use [Self::set_stage_timeout] and
+    /// [Self::set_stage_action] instead for a multi-stage configuration.
     pub fn set_timeout(&mut self, timeout: MicrosDurationU64) {
         let timeout_raw = (timeout.to_nanos() * 10 / 125) as u32;
 
@@ -959,9 +1389,9 @@ where
                 .wdt_stg0()
                 .bits(3)
                 .wdt_cpu_reset_length()
-                .bits(1)
+                .bits(self.cpu_reset_length)
                 .wdt_sys_reset_length()
-                .bits(1)
+                .bits(self.sys_reset_length)
                 .wdt_stg1()
                 .bits(0)
                 .wdt_stg2()
@@ -979,6 +1409,172 @@ where
             .wdtwprotect()
             .write(|w| unsafe { w.wdt_wkey().bits(0u32) });
     }
+
+    /// Set the timeout, in microseconds, of a single stage of the watchdog
+    /// timer
+    ///
+    /// Unlike [Self::set_timeout] this doesn't touch the configuration of the
+    /// other stages.
+    pub fn set_stage_timeout(&mut self, stage: MwdtStage, timeout: MicrosDurationU64) {
+        let timeout_raw = (timeout.to_nanos() * 10 / 125) as u32;
+
+        let reg_block = unsafe { &*TG::register_block() };
+
+        reg_block
+            .wdtwprotect()
+            .write(|w| unsafe { w.wdt_wkey().bits(0x50D8_3AA1u32) });
+
+        reg_block
+            .wdtconfig1()
+            .write(|w| unsafe { w.wdt_clk_prescale().bits(1) });
+
+        unsafe {
+            match stage {
+                MwdtStage::Stage0 => reg_block
+                    .wdtconfig2()
+                    .write(|w| w.wdt_stg0_hold().bits(timeout_raw)),
+                MwdtStage::Stage1 => reg_block
+                    .wdtconfig3()
+                    .write(|w| w.wdt_stg1_hold().bits(timeout_raw)),
+                MwdtStage::Stage2 => reg_block
+                    .wdtconfig4()
+                    .write(|w| w.wdt_stg2_hold().bits(timeout_raw)),
+                MwdtStage::Stage3 => reg_block
+                    .wdtconfig5()
+                    .write(|w| w.wdt_stg3_hold().bits(timeout_raw)),
+            }
+        }
+
+        #[cfg(any(esp32c2, esp32c3, esp32c6))]
+        reg_block
+            .wdtconfig0()
+            .modify(|_, w| w.wdt_conf_update_en().set_bit());
+
+        reg_block
+            .wdtwprotect()
+            .write(|w| unsafe { w.wdt_wkey().bits(0u32) });
+    }
+
+    /// Set the action taken when the given stage of the watchdog timer times
+    /// out
+    ///
+    /// Note that [Self::set_timeout] overrides the actions of all stages.
+    pub fn set_stage_action(&mut self, stage: MwdtStage, action: MwdtStageAction) {
+        let reg_block = unsafe { &*TG::register_block() };
+
+        reg_block
+            .wdtwprotect()
+            .write(|w| unsafe { w.wdt_wkey().bits(0x50D8_3AA1u32) });
+
+        #[cfg_attr(esp32, allow(unused_unsafe))]
+        reg_block.wdtconfig0().modify(|_, w| unsafe {
+            match stage {
+                MwdtStage::Stage0 => w.wdt_stg0().bits(action as u8),
+                MwdtStage::Stage1 => w.wdt_stg1().bits(action as u8),
+                MwdtStage::Stage2 => w.wdt_stg2().bits(action as u8),
+                MwdtStage::Stage3 => w.wdt_stg3().bits(action as u8),
+            }
+        });
+
+        #[cfg(any(esp32c2, esp32c3, esp32c6))]
+        reg_block
+            .wdtconfig0()
+            .modify(|_, w| w.wdt_conf_update_en().set_bit());
+
+        reg_block
+            .wdtwprotect()
+            .write(|w| unsafe { w.wdt_wkey().bits(0u32) });
+    }
+
+    /// Returns the timeout of the given stage of the watchdog timer
+    ///
+    /// The prescaler of the watchdog clock is taken into account, so this
+    /// also reports timeouts which weren't configured via this driver.
+    pub fn stage_timeout(&self, stage: MwdtStage) -> MicrosDurationU64 {
+        let reg_block = unsafe { &*TG::register_block() };
+
+        let prescale = reg_block.wdtconfig1().read().wdt_clk_prescale().bits() as u64;
+        let hold = match stage {
+            MwdtStage::Stage0 => reg_block.wdtconfig2().read().wdt_stg0_hold().bits(),
+            MwdtStage::Stage1 => reg_block.wdtconfig3().read().wdt_stg1_hold().bits(),
+            MwdtStage::Stage2 => reg_block.wdtconfig4().read().wdt_stg2_hold().bits(),
+            MwdtStage::Stage3 => reg_block.wdtconfig5().read().wdt_stg3_hold().bits(),
+        } as u64;
+
+        // one tick of the watchdog clock is 12.5ns times the prescaler
+        MicrosDurationU64::micros(hold * prescale * 125 / 10 / 1000)
+    }
+
+    /// Returns the action taken when the given stage of the watchdog timer
+    /// times out
+    pub fn stage_action(&self, stage: MwdtStage) -> MwdtStageAction {
+        let reg_block = unsafe { &*TG::register_block() };
+
+        let config = reg_block.wdtconfig0().read();
+        let action = match stage {
+            MwdtStage::Stage0 => config.wdt_stg0().bits(),
+            MwdtStage::Stage1 => config.wdt_stg1().bits(),
+            MwdtStage::Stage2 => config.wdt_stg2().bits(),
+            MwdtStage::Stage3 => config.wdt_stg3().bits(),
+        };
+
+        match action {
+            0 => MwdtStageAction::Off,
+            1 => MwdtStageAction::Interrupt,
+            2 => MwdtStageAction::ResetCpu,
+            _ => MwdtStageAction::ResetSystem,
+        }
+    }
+
+    /// Listen for the watchdog interrupt
+    ///
+    /// The interrupt is triggered by stages configured with
+    /// [MwdtStageAction::Interrupt].
+    pub fn listen(&mut self) {
+        let reg_block = unsafe { &*TG::register_block() };
+
+        reg_block.int_ena_timers().modify(|_, w| w.wdt().set_bit());
+    }
+
+    /// Stop listening for the watchdog interrupt
+    pub fn unlisten(&mut self) {
+        let reg_block = unsafe { &*TG::register_block() };
+
+        reg_block.int_ena_timers().modify(|_, w| w.wdt().clear_bit());
+    }
+
+    /// Clear the watchdog interrupt
+    pub fn clear_interrupt(&mut self) {
+        let reg_block = unsafe { &*TG::register_block() };
+
+        reg_block.int_clr_timers().write(|w| w.wdt().clear_bit_by_one());
+    }
+
+    /// Returns the stage which triggered the watchdog interrupt, if the
+    /// interrupt is pending
+    ///
+    /// The hardware only reports that the interrupt fired, so this returns the
+    /// first stage configured with [MwdtStageAction::Interrupt]. With a
+    /// configuration like `Stage0 = Interrupt, Stage1 = ResetSystem` this
+    /// allows an interrupt handler to react before the reset happens.
+    pub fn interrupt_stage(&self) -> Option<MwdtStage> {
+        let reg_block = unsafe { &*TG::register_block() };
+
+        if reg_block.int_raw_timers().read().wdt().bit_is_clear() {
+            return None;
+        }
+
+        let config = reg_block.wdtconfig0().read();
+        let interrupt = MwdtStageAction::Interrupt as u8;
+        [
+            (MwdtStage::Stage0, config.wdt_stg0().bits()),
+            (MwdtStage::Stage1, config.wdt_stg1().bits()),
+            (MwdtStage::Stage2, config.wdt_stg2().bits()),
+            (MwdtStage::Stage3, config.wdt_stg3().bits()),
+        ]
+        .into_iter()
+        .find_map(|(stage, action)| (action == interrupt).then_some(stage))
+    }
 }
 
 impl<TG, DM> Default for Wdt<TG, DM>
@@ -1030,6 +1626,143 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<T> Timer<T, crate::Async>
+where
+    T: Instance,
+{
+    /// Wait for the alarm of the timer to fire.
+    ///
+    /// The alarm needs to be configured and the timer started before, e.g.
+    /// via `load_value` and `start`. The interrupt is cleared once the alarm
+    /// fired. If the alarm already fired before calling this, it returns
+    /// immediately. It also returns immediately if the timer group is
+    /// suspended, see [`TimerGroup::suspend`].
+    pub async fn wait_async(&mut self) {
+        if is_suspended(self.timg.timer_group()) {
+            return;
+        }
+
+        asynch::AlarmFuture::new(&self.timg).await;
+    }
+}
+
+// Async functionality of the timer group timers.
+#[cfg(feature = "async")]
+mod asynch {
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use embassy_sync::waitqueue::AtomicWaker;
+    use procmacros::handler;
+
+    use super::*;
+
+    const NUM_WAKERS: usize = 4;
+
+    const INIT: AtomicWaker = AtomicWaker::new();
+    static WAKERS: [AtomicWaker; NUM_WAKERS] = [INIT; NUM_WAKERS];
+
+    fn waker_index(timer_group: u8, timer: u8) -> usize {
+        timer_group as usize * 2 + timer as usize
+    }
+
+    pub(crate) struct AlarmFuture<'a, T>
+    where
+        T: Instance,
+    {
+        timer: &'a T,
+    }
+
+    impl<'a, T> AlarmFuture<'a, T>
+    where
+        T: Instance,
+    {
+        pub(crate) fn new(timer: &'a T) -> Self {
+            // Don't clear the interrupt here, the alarm may already have fired.
+            // It's cleared when the alarm is armed.
+            let (interrupt, handler) = match (timer.timer_group(), timer.timer_number()) {
+                (0, 0) => (Interrupt::TG0_T0_LEVEL, tg0_t0_handler),
+                #[cfg(timg_timer1)]
+                (0, 1) => (Interrupt::TG0_T1_LEVEL, tg0_t1_handler),
+                #[cfg(timg1)]
+                (1, 0) => (Interrupt::TG1_T0_LEVEL, tg1_t0_handler),
+                #[cfg(all(timg1, timg_timer1))]
+                (1, 1) => (Interrupt::TG1_T1_LEVEL, tg1_t1_handler),
+                _ => unreachable!(),
+            };
+
+            unsafe {
+                interrupt::bind_interrupt(interrupt, handler.handler());
+                interrupt::enable(interrupt, handler.priority()).unwrap();
+            }
+
+            timer.listen();
+
+            Self { timer }
+        }
+
+        fn event_bit_is_clear(&self) -> bool {
+            self.timer
+                .register_block()
+                .int_ena_timers()
+                .read()
+                .t(self.timer.timer_number())
+                .bit_is_clear()
+        }
+    }
+
+    impl<'a, T> core::future::Future for AlarmFuture<'a, T>
+    where
+        T: Instance,
+    {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+            let index = waker_index(self.timer.timer_group(), self.timer.timer_number());
+            WAKERS[index].register(ctx.waker());
+
+            if self.event_bit_is_clear() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    fn handle_alarm(regs: &RegisterBlock, timer_group: u8, timer: u8) {
+        regs.int_ena_timers().modify(|_, w| w.t(timer).clear_bit());
+        regs.int_clr_timers().write(|w| w.t(timer).clear_bit_by_one());
+
+        WAKERS[waker_index(timer_group, timer)].wake();
+    }
+
+    #[handler]
+    fn tg0_t0_handler() {
+        handle_alarm(unsafe { &*TIMG0::PTR }, 0, 0);
+    }
+
+    #[cfg(timg_timer1)]
+    #[handler]
+    fn tg0_t1_handler() {
+        handle_alarm(unsafe { &*TIMG0::PTR }, 0, 1);
+    }
+
+    #[cfg(timg1)]
+    #[handler]
+    fn tg1_t0_handler() {
+        handle_alarm(unsafe { &*TIMG1::PTR }, 1, 0);
+    }
+
+    #[cfg(all(timg1, timg_timer1))]
+    #[handler]
+    fn tg1_t1_handler() {
+        handle_alarm(unsafe { &*TIMG1::PTR }, 1, 1);
+    }
+}
+
 /// Event Task Matrix
 #[cfg(soc_etm)]
 pub mod etm {
@@ -907,6 +907,34 @@ where
             .bit_is_set()
     }
 
+    /// Start the bus-off recovery.
+    ///
+    /// The controller enters reset mode when it goes bus off. Leaving reset
+    /// mode starts the recovery, which completes after the controller has
+    /// monitored 128 occurrences of 11 consecutive recessive bits on the bus.
+    /// Use [Self::is_bus_off] or [Self::status] to check if it completed.
+    ///
+    /// The configuration (e.g. the filter) is kept.
+    pub fn initiate_bus_off_recovery(&mut self) {
+        T::register_block()
+            .mode()
+            .modify(|_, w| w.reset_mode().clear_bit());
+    }
+
+    /// Get the error state of the controller.
+    ///
+    /// The individual error counters are available via
+    /// [Self::transmit_error_count] and [Self::receive_error_count].
+    pub fn status(&self) -> TwaiStatus {
+        if self.is_bus_off() {
+            TwaiStatus::BusOff
+        } else if self.transmit_error_count() >= 128 || self.receive_error_count() >= 128 {
+            TwaiStatus::ErrorPassive
+        } else {
+            TwaiStatus::ErrorActive
+        }
+    }
+
     /// Get the number of messages that the peripheral has available in the
     /// receive FIFO.
     ///
@@ -937,6 +965,13 @@ where
         self.tx.transmit(frame)
     }
 
+    /// Transmit as many of the given frames as possible without blocking.
+    ///
+    /// See [TwaiTx::transmit_all].
+    pub fn transmit_all(&mut self, frames: &[EspTwaiFrame]) -> Result<usize, EspTwaiError> {
+        self.tx.transmit_all(frames)
+    }
+
     pub fn receive(&mut self) -> nb::Result<EspTwaiFrame, EspTwaiError> {
         self.rx.receive()
     }
@@ -988,6 +1023,28 @@ where
 
         Ok(())
     }
+
+    /// Transmit as many of the given frames as possible without blocking.
+    ///
+    /// Returns the number of frames which were queued, the remaining frames
+    /// need to be passed again later. Note that the peripheral only has a
+    /// single transmit buffer, so this queues at most one frame while a
+    /// previous transmission is still in progress.
+    ///
+    /// An error is only returned if no frame could be queued, otherwise it's
+    /// reported by the next call.
+    pub fn transmit_all(&mut self, frames: &[EspTwaiFrame]) -> Result<usize, EspTwaiError> {
+        for (queued, frame) in frames.iter().enumerate() {
+            match self.transmit(frame) {
+                Ok(()) => {}
+                Err(nb::Error::WouldBlock) => return Ok(queued),
+                Err(nb::Error::Other(_)) if queued > 0 => return Ok(queued),
+                Err(nb::Error::Other(error)) => return Err(error),
+            }
+        }
+
+        Ok(frames.len())
+    }
 }
 
 /// Interface to the CAN receiver part.
@@ -1001,7 +1058,10 @@ where
     T: OperationInstance,
     DM: crate::Mode,
 {
-    // Receive a frame
+    /// Receive a frame
+    ///
+    /// If the receive FIFO overran, the frames in the FIFO are discarded and
+    /// `EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)` is returned once.
     pub fn receive(&mut self) -> nb::Result<EspTwaiFrame, EspTwaiError> {
         let register_block = T::register_block();
         let status = register_block.status().read();
@@ -1018,6 +1078,10 @@ where
 
         // Check if the packet in the receive buffer is valid or overrun.
         if status.miss_st().bit_is_set() {
+            // Discard the FIFO contents and clear the overrun so receiving can
+            // continue with the next frame.
+            T::clear_overrun();
+
             return nb::Result::Err(nb::Error::Other(EspTwaiError::EmbeddedHAL(
                 ErrorKind::Overrun,
             )));
@@ -1032,6 +1096,20 @@ where
     }
 }
 
+/// Error state of the TWAI controller
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TwaiStatus {
+    /// Both error counters are below 128, the controller takes part in the
+    /// bus communication normally.
+    ErrorActive,
+    /// At least one of the error counters reached 128, the controller only
+    /// sends passive error flags.
+    ErrorPassive,
+    /// The transmit error counter exceeded 255, the controller doesn't take
+    /// part in the bus communication anymore.
+    BusOff,
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum EspTwaiError {
     BusOff,
@@ -1173,6 +1251,24 @@ pub trait OperationInstance: Instance {
             .write(|w| w.release_buf().set_bit());
     }
 
+    /// Release all messages in the receive FIFO and clear the data overrun
+    /// status.
+    fn clear_overrun() {
+        let register_block = Self::register_block();
+
+        while register_block
+            .rx_message_cnt()
+            .read()
+            .rx_message_counter()
+            .bits()
+            > 0
+        {
+            Self::release_receive_fifo();
+        }
+
+        register_block.cmd().write(|w| w.clr_overrun().set_bit());
+    }
+
     /// Write a frame to the peripheral.
     fn write_frame(frame: &EspTwaiFrame) {
         // Assemble the frame information into the data_0 byte.
@@ -1352,6 +1448,8 @@ impl Instance for crate::peripherals::TWAI0 {
                 .set_bit()
                 .err_passive_int_ena()
                 .set_bit()
+                .err_warn_int_ena()
+                .set_bit()
         });
     }
 }
@@ -1396,6 +1494,8 @@ impl Instance for crate::peripherals::TWAI0 {
                 .set_bit()
                 .err_passive_int_ena()
                 .set_bit()
+                .ext_error_warning_int_ena()
+                .set_bit()
         });
     }
 }
@@ -1440,6 +1540,8 @@ impl Instance for crate::peripherals::TWAI1 {
                 .set_bit()
                 .err_passive_int_ena()
                 .set_bit()
+                .ext_error_warning_int_ena()
+                .set_bit()
         });
     }
 }
@@ -1494,6 +1596,32 @@ mod asynch {
         pub async fn receive_async(&mut self) -> Result<EspTwaiFrame, EspTwaiError> {
             self.rx.receive_async().await
         }
+
+        /// Recover from the bus off state.
+        ///
+        /// Starts the bus-off recovery (see [Twai::initiate_bus_off_recovery])
+        /// and waits until the controller is no longer bus off. Returns
+        /// immediately if the controller isn't bus off.
+        pub async fn recover_bus_off(&mut self) {
+            if !self.is_bus_off() {
+                return;
+            }
+
+            self.initiate_bus_off_recovery();
+
+            poll_fn(|cx| {
+                T::async_state().err_waker.register(cx.waker());
+                // Leaving the bus off state raises the error warning interrupt
+                T::enable_interrupts();
+
+                if self.is_bus_off() {
+                    Poll::Pending
+                } else {
+                    Poll::Ready(())
+                }
+            })
+            .await
+        }
     }
 
     impl<'d, T> TwaiTx<'d, T, crate::Async>
@@ -1547,6 +1675,7 @@ mod asynch {
 
                     // Check if the packet in the receive buffer is valid or overrun.
                     if status.miss_st().bit_is_set() {
+                        T::clear_overrun();
                         return Poll::Ready(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)));
                     }
                 }
@@ -1580,15 +1709,22 @@ mod asynch {
                 let _ = rx_queue.try_send(Err(EspTwaiError::BusOff));
             }
 
-            if status.miss_st().bit_is_set() {
-                let _ = rx_queue.try_send(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)));
-            }
+            let overrun_queued = status.miss_st().bit_is_set()
+                && rx_queue
+                    .try_send(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)))
+                    .is_ok();
 
             let frame = TWAI0::read_frame();
 
             let _ = rx_queue.try_send(Ok(frame));
 
             register_block.cmd().write(|w| w.release_buf().set_bit());
+
+            // Only clear the overrun once it's queued, otherwise `receive_async`
+            // reports it from the status register
+            if overrun_queued {
+                register_block.cmd().write(|w| w.clr_overrun().set_bit());
+            }
         }
 
         if intr_status.bits() & 0b11111100 > 0 {
@@ -1625,15 +1761,22 @@ mod asynch {
                 let _ = rx_queue.try_send(Err(EspTwaiError::BusOff));
             }
 
-            if status.miss_st().bit_is_set() {
-                let _ = rx_queue.try_send(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)));
-            }
+            let overrun_queued = status.miss_st().bit_is_set()
+                && rx_queue
+                    .try_send(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)))
+                    .is_ok();
 
             let frame = TWAI0::read_frame();
 
             let _ = rx_queue.try_send(Ok(frame));
 
             register_block.cmd().write(|w| w.release_buf().set_bit());
+
+            // Only clear the overrun once it's queued, otherwise `receive_async`
+            // reports it from the status register
+            if overrun_queued {
+                register_block.cmd().write(|w| w.clr_overrun().set_bit());
+            }
         }
 
         if intr_status.bits() & 0b11111100 > 0 {
@@ -1670,15 +1813,22 @@ mod asynch {
                 let _ = rx_queue.try_send(Err(EspTwaiError::BusOff));
             }
 
-            if status.miss_st().bit_is_set() {
-                let _ = rx_queue.try_send(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)));
-            }
+            let overrun_queued = status.miss_st().bit_is_set()
+                && rx_queue
+                    .try_send(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)))
+                    .is_ok();
 
             let frame = TWAI1::read_frame();
 
             let _ = rx_queue.try_send(Ok(frame));
 
             register_block.cmd().write(|w| w.release_buf().set_bit());
+
+            // Only clear the overrun once it's queued, otherwise `receive_async`
+            // reports it from the status register
+            if overrun_queued {
+                register_block.cmd().write(|w| w.clr_overrun().set_bit());
+            }
         }
 
         if intr_status.bits() & 0b11111100 > 0 {
@@ -12,7 +12,7 @@
 use core::{cell::RefCell, fmt::Debug, marker::PhantomData};
 
 use critical_section::Mutex;
-use portable_atomic::{AtomicBool, AtomicU8, Ordering};
+use portable_atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
 
 use crate::{
     binary::include::*,
@@ -27,7 +27,10 @@ pub const ESP_NOW_MAX_DATA_LEN: usize = 250;
 /// Broadcast address
 pub const BROADCAST_ADDRESS: [u8; 6] = [0xffu8, 0xffu8, 0xffu8, 0xffu8, 0xffu8, 0xffu8];
 
-static RECEIVE_QUEUE: Mutex<RefCell<SimpleQueue<ReceivedData, 10>>> =
+const RECEIVE_QUEUE_SIZE: usize = crate::CONFIG.esp_now_rx_queue_size;
+
+// `SimpleQueue` can hold one element less than its capacity
+static RECEIVE_QUEUE: Mutex<RefCell<SimpleQueue<ReceivedData, { RECEIVE_QUEUE_SIZE + 1 }>>> =
     Mutex::new(RefCell::new(SimpleQueue::new()));
 /// This atomic behaves like a guard, so we need strict memory ordering when
 /// operating it.
@@ -37,6 +40,34 @@ static RECEIVE_QUEUE: Mutex<RefCell<SimpleQueue<ReceivedData, 10>>> =
 static ESP_NOW_SEND_CB_INVOKED: AtomicBool = AtomicBool::new(false);
 /// Status of esp now send, true for success, false for failure
 static ESP_NOW_SEND_STATUS: AtomicBool = AtomicBool::new(true);
+/// Number of sendings started by `send_nowait` whose send callback has not
+/// been invoked yet. Their callbacks don't signal the send waiters.
+static ESP_NOW_NOWAIT_PENDING: AtomicUsize = AtomicUsize::new(0);
+/// Callback notified about the result of every sending
+static SEND_RESULT_CALLBACK: Mutex<RefCell<Option<fn([u8; 6], bool)>>> =
+    Mutex::new(RefCell::new(None));
+/// Whether the ESP-NOW channel follows the channel of the STA association
+static FOLLOW_STA_CHANNEL: AtomicBool = AtomicBool::new(false);
+/// Set when the STA (re-)associated and the channel needs to be synchronized
+static STA_CHANNEL_SYNC_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// Peer whose RSSI drives the rate adaptation, if enabled
+static RATE_ADAPTATION_PEER: Mutex<RefCell<Option<[u8; 6]>>> = Mutex::new(RefCell::new(None));
+/// Index into `RATE_ADAPTATION_TABLE` of the currently selected rate
+static RATE_ADAPTATION_LEVEL: AtomicU8 = AtomicU8::new(0);
+/// Set when the rate adaptation selected a new rate which is not applied yet
+static RATE_ADAPTATION_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// Rates used by the rate adaptation, from the most robust to the fastest,
+/// together with the minimum RSSI (in dBm) to use them
+const RATE_ADAPTATION_TABLE: [(i32, u32); 4] = [
+    (i32::MIN, WifiPhyRate::Rate1mL as u32),
+    (-80, WifiPhyRate::Rate6m as u32),
+    (-70, WifiPhyRate::Rate12m as u32),
+    (-60, WifiPhyRate::Rate24m as u32),
+];
+/// Margin (in dB) the RSSI must exceed a threshold by to switch to a faster rate
+const RATE_ADAPTATION_HYSTERESIS: i32 = 5;
 
 macro_rules! check_error {
     ($block:block) => {
@@ -85,6 +116,8 @@ pub enum EspNowError {
     SendFailed,
     /// Attempt to create EspNow instance twice
     DuplicateInstance,
+    /// Attempt to set the channel while it follows the STA channel
+    FollowingStaChannel,
 }
 
 #[derive(Debug)]
@@ -167,6 +200,73 @@ pub enum WifiPhyRate {
     RateMax,
 }
 
+impl WifiPhyRate {
+    /// Nominal bitrate of the rate in kbps
+    ///
+    /// The bandwidth only affects the HT (MCS) rates. Returns 0 for
+    /// [WifiPhyRate::RateMax].
+    pub fn bitrate_kbps(&self, bandwidth: Bandwidth) -> u32 {
+        let ht = |ht20, ht40| match bandwidth {
+            Bandwidth::Ht20 => ht20,
+            Bandwidth::Ht40 => ht40,
+        };
+
+        match self {
+            Self::Rate1mL => 1_000,
+            Self::Rate2m | Self::Rate2mS => 2_000,
+            Self::Rate5mL | Self::Rate5mS => 5_500,
+            Self::Rate11mL | Self::Rate11mS => 11_000,
+            Self::Rate48m => 48_000,
+            Self::Rate24m => 24_000,
+            Self::Rate12m => 12_000,
+            Self::Rate6m => 6_000,
+            Self::Rate54m => 54_000,
+            Self::Rate36m => 36_000,
+            Self::Rate18m => 18_000,
+            Self::Rate9m => 9_000,
+            Self::RateMcs0Lgi => ht(6_500, 13_500),
+            Self::RateMcs1Lgi => ht(13_000, 27_000),
+            Self::RateMcs2Lgi => ht(19_500, 40_500),
+            Self::RateMcs3Lgi => ht(26_000, 54_000),
+            Self::RateMcs4Lgi => ht(39_000, 81_000),
+            Self::RateMcs5Lgi => ht(52_000, 108_000),
+            Self::RateMcs6Lgi => ht(58_500, 121_500),
+            Self::RateMcs7Lgi => ht(65_000, 135_000),
+            Self::RateMcs0Sgi => ht(7_200, 15_000),
+            Self::RateMcs1Sgi => ht(14_400, 30_000),
+            Self::RateMcs2Sgi => ht(21_700, 45_000),
+            Self::RateMcs3Sgi => ht(28_900, 60_000),
+            Self::RateMcs4Sgi => ht(43_300, 90_000),
+            Self::RateMcs5Sgi => ht(57_800, 120_000),
+            Self::RateMcs6Sgi => ht(65_000, 135_000),
+            Self::RateMcs7Sgi => ht(72_200, 150_000),
+            Self::RateLora250k => 250,
+            Self::RateLora500k => 500,
+            Self::RateMax => 0,
+        }
+    }
+}
+
+/// Channel bandwidth, used to look up the bitrate of a [WifiPhyRate]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Bandwidth {
+    /// 20MHz channel
+    Ht20,
+    /// 40MHz channel
+    Ht40,
+}
+
+/// WiFi interface used by ESP-NOW
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EspNowWifiInterface {
+    /// Access point interface
+    Ap,
+    /// Station interface
+    Sta,
+}
+
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct PeerInfo {
@@ -234,6 +334,8 @@ pub struct ReceiveInfo {
     pub src_address: [u8; 6],
     pub dst_address: [u8; 6],
     pub rx_control: RxControlInfo,
+    /// Time at which the frame was handed to the receive callback
+    pub timestamp: fugit::Instant<u64, 1, 1_000_000>,
 }
 
 #[derive(Clone, Copy)]
@@ -276,10 +378,34 @@ pub struct EspNowManager<'d> {
 impl<'d> EspNowManager<'d> {
     /// Set primary WiFi channel
     /// Should only be used when using ESP-NOW without AP or STA
+    ///
+    /// This returns [EspNowError::FollowingStaChannel] while
+    /// [EspNowManager::follow_sta_channel] is enabled.
     pub fn set_channel(&self, channel: u8) -> Result<(), EspNowError> {
+        if FOLLOW_STA_CHANNEL.load(Ordering::Acquire) {
+            return Err(EspNowError::FollowingStaChannel);
+        }
+
         check_error!({ esp_wifi_set_channel(channel, 0) })
     }
 
+    /// Lock the ESP-NOW channel to the channel of the AP the STA is
+    /// associated with
+    ///
+    /// While enabled, the channel is synchronized on the next send after the
+    /// STA (re-)connected, e.g. after roaming, and
+    /// [EspNowManager::set_channel] returns an error.
+    pub fn follow_sta_channel(&self, enable: bool) -> Result<(), EspNowError> {
+        FOLLOW_STA_CHANNEL.store(enable, Ordering::Release);
+
+        if enable {
+            STA_CHANNEL_SYNC_PENDING.store(true, Ordering::Release);
+            sync_sta_channel()?;
+        }
+
+        Ok(())
+    }
+
     /// Get the version of ESPNOW
     pub fn get_version(&self) -> Result<u32, EspNowError> {
         let mut version = 0u32;
@@ -289,20 +415,20 @@ impl<'d> EspNowManager<'d> {
 
     /// Add a peer to the list of known peers
     pub fn add_peer(&self, peer: PeerInfo) -> Result<(), EspNowError> {
-        let raw_peer = esp_now_peer_info_t {
-            peer_addr: peer.peer_address,
-            lmk: peer.lmk.unwrap_or([0u8; 16]),
-            channel: peer.channel.unwrap_or(0),
-            ifidx: wifi_interface_t_WIFI_IF_STA,
-            encrypt: peer.encrypt,
-            priv_: core::ptr::null_mut(),
-        };
-        check_error!({ esp_now_add_peer(&raw_peer as *const _) })
+        add_peer(peer)
+    }
+
+    /// Add a peer to the list of known peers unless it is already known
+    ///
+    /// Returns `Ok(true)` if the peer was added and `Ok(false)` if it already
+    /// existed.
+    pub fn add_peer_if_absent(&self, peer: PeerInfo) -> Result<bool, EspNowError> {
+        add_peer_if_absent(peer)
     }
 
     /// Remove the given peer
     pub fn remove_peer(&self, peer_address: &[u8; 6]) -> Result<(), EspNowError> {
-        check_error!({ esp_now_del_peer(peer_address.as_ptr()) })
+        remove_peer(peer_address)
     }
 
     /// Modify a peer information
@@ -378,11 +504,45 @@ impl<'d> EspNowManager<'d> {
         })
     }
 
+    /// Iterate over the peer list
+    ///
+    /// The broadcast peer is returned first if it's in the peer list, followed
+    /// by all unicast peers. Other multicast peers are skipped since the
+    /// driver doesn't provide a way to enumerate them.
+    ///
+    /// Adding or removing peers while iterating restarts or ends the
+    /// iteration.
+    pub fn peers(&self) -> impl Iterator<Item = PeerInfo> + '_ {
+        let broadcast = self.get_peer(&BROADCAST_ADDRESS).ok();
+        let mut from_head = true;
+
+        broadcast.into_iter().chain(core::iter::from_fn(move || {
+            let peer = self.fetch_peer(from_head).ok();
+            from_head = false;
+            peer
+        }))
+    }
+
     /// Check is peer is known
     pub fn peer_exists(&self, peer_address: &[u8; 6]) -> bool {
         unsafe { esp_now_is_peer_exist(peer_address.as_ptr()) }
     }
 
+    /// Get the MAC address of the given interface
+    ///
+    /// Other devices need this address to add this device as a peer.
+    pub fn interface_mac(&self, interface: EspNowWifiInterface) -> Result<[u8; 6], EspNowError> {
+        let interface = match interface {
+            EspNowWifiInterface::Ap => wifi_interface_t_WIFI_IF_AP,
+            EspNowWifiInterface::Sta => wifi_interface_t_WIFI_IF_STA,
+        };
+
+        let mut mac = [0u8; 6];
+        check_error!({ esp_wifi_get_mac(interface, mac.as_mut_ptr()) })?;
+
+        Ok(mac)
+    }
+
     /// Get the number of peers
     pub fn peer_count(&self) -> Result<PeerCount, EspNowError> {
         let mut peer_num = esp_now_peer_num_t {
@@ -414,6 +574,36 @@ impl<'d> EspNowManager<'d> {
     pub fn set_rate(&self, rate: WifiPhyRate) -> Result<(), EspNowError> {
         check_error!({ esp_wifi_config_espnow_rate(wifi_interface_t_WIFI_IF_STA, rate as u32,) })
     }
+
+    /// Automatically select the PHY rate based on the RSSI of frames received
+    /// from the given peer
+    ///
+    /// The rate is lowered as soon as the RSSI drops below the threshold of
+    /// the current rate. To avoid flapping between two rates, a faster rate is
+    /// only selected once the RSSI exceeds its threshold by 5 dB. The rate
+    /// starts at the most robust one (1 Mbps) and a newly selected rate is
+    /// applied with the next sending.
+    ///
+    /// Calling [EspNowManager::set_rate] while the rate adaptation is enabled
+    /// has only effect until the rate adaptation selects a new rate.
+    pub fn enable_rate_adaptation(&self, peer: [u8; 6]) {
+        critical_section::with(|cs| {
+            RATE_ADAPTATION_PEER.borrow_ref_mut(cs).replace(peer);
+            RATE_ADAPTATION_LEVEL.store(0, Ordering::Relaxed);
+            RATE_ADAPTATION_PENDING.store(true, Ordering::Release);
+        });
+    }
+
+    /// Disable the rate adaptation enabled by
+    /// [EspNowManager::enable_rate_adaptation]
+    ///
+    /// The currently selected rate is kept.
+    pub fn disable_rate_adaptation(&self) {
+        critical_section::with(|cs| {
+            RATE_ADAPTATION_PEER.borrow_ref_mut(cs).take();
+            RATE_ADAPTATION_PENDING.store(false, Ordering::Release);
+        });
+    }
 }
 
 /// This is the sender part of ESP-NOW. You can get this sender by splitting
@@ -436,10 +626,90 @@ impl<'d> EspNowSender<'d> {
         dst_addr: &[u8; 6],
         data: &[u8],
     ) -> Result<SendWaiter<'s>, EspNowError> {
+        prepare_send()?;
         ESP_NOW_SEND_CB_INVOKED.store(false, Ordering::Release);
         check_error!({ esp_now_send(dst_addr.as_ptr(), data.as_ptr(), data.len()) })?;
         Ok(SendWaiter(PhantomData))
     }
+
+    /// Send data to all devices in range
+    ///
+    /// The broadcast peer is always in the peer list, it's added when
+    /// ESP-NOW is initialized.
+    pub fn broadcast<'s>(&'s mut self, data: &[u8]) -> Result<SendWaiter<'s>, EspNowError> {
+        self.send(&BROADCAST_ADDRESS, data)
+    }
+
+    /// Send data to peer without waiting for the sending to complete
+    ///
+    /// The peer needs to be added to the peer list first.
+    ///
+    /// The result of the sending is discarded, use
+    /// [EspNowSender::on_send_result] to get notified about it.
+    ///
+    /// The WiFi stack serializes the sendings, so they complete in the order
+    /// they were started. Another sending can be started right away: a
+    /// following [EspNowSender::send] (or async send) completes only after
+    /// this one, and its [SendWaiter] reports the status of its own sending,
+    /// never the one of this sending.
+    pub fn send_nowait(&mut self, dst_addr: &[u8; 6], data: &[u8]) -> Result<(), EspNowError> {
+        prepare_send()?;
+        ESP_NOW_NOWAIT_PENDING.fetch_add(1, Ordering::AcqRel);
+        let res = check_error!({ esp_now_send(dst_addr.as_ptr(), data.as_ptr(), data.len()) });
+        if res.is_err() {
+            ESP_NOW_NOWAIT_PENDING.fetch_sub(1, Ordering::AcqRel);
+        }
+        res
+    }
+
+    /// Send data to a peer, adding it to the peer list if it's unknown
+    ///
+    /// An unknown destination is added as an unencrypted peer on the current
+    /// channel. If `remove_after` is set, a peer added by this call is removed
+    /// again once the sending completed. Peers which were already known are
+    /// never removed.
+    ///
+    /// This waits for the sending to complete. If the peer list is full,
+    /// [Error::PeerListFull] is returned.
+    pub fn send_auto(
+        &mut self,
+        dst_addr: &[u8; 6],
+        data: &[u8],
+        remove_after: bool,
+    ) -> Result<(), EspNowError> {
+        let added = add_peer_if_absent(PeerInfo {
+            peer_address: *dst_addr,
+            lmk: None,
+            channel: None,
+            encrypt: false,
+        })?;
+
+        let res = self.send(dst_addr, data).and_then(|waiter| waiter.wait());
+
+        // The result of the sending is more relevant than a failed cleanup
+        if added && remove_after && remove_peer(dst_addr).is_err() {
+            warn!("Failed to remove peer {:?} after sending", dst_addr);
+        }
+
+        res
+    }
+
+    /// Register a callback which is notified about the result of every
+    /// sending
+    ///
+    /// The callback gets the MAC address of the destination and whether the
+    /// sending succeeded, which allows correlating failures to peers when
+    /// using [EspNowSender::send_nowait]. It's invoked from the send
+    /// callback of the WiFi driver, so it should return quickly. Pass `None`
+    /// to remove the callback.
+    ///
+    /// This is independent of [SendWaiter] and the async API, which still
+    /// report the status of their own sending.
+    pub fn on_send_result(&mut self, callback: Option<fn([u8; 6], bool)>) {
+        critical_section::with(|cs| {
+            *SEND_RESULT_CALLBACK.borrow_ref_mut(cs) = callback;
+        });
+    }
 }
 
 /// This struct is returned by a sync esp now send. Invoking `wait` method of
@@ -493,6 +763,11 @@ impl<'d> EspNowReceiver<'d> {
             queue.dequeue()
         })
     }
+
+    /// Number of received frames waiting to be read.
+    pub fn pending(&self) -> usize {
+        critical_section::with(|cs| RECEIVE_QUEUE.borrow_ref(cs).len())
+    }
 }
 
 /// The reference counter for properly deinit espnow after all parts are
@@ -636,10 +911,23 @@ impl<'d> EspNow<'d> {
 
     /// Set primary WiFi channel
     /// Should only be used when using ESP-NOW without AP or STA
+    ///
+    /// This returns [EspNowError::FollowingStaChannel] while
+    /// [EspNow::follow_sta_channel] is enabled.
     pub fn set_channel(&self, channel: u8) -> Result<(), EspNowError> {
         self.manager.set_channel(channel)
     }
 
+    /// Lock the ESP-NOW channel to the channel of the AP the STA is
+    /// associated with
+    ///
+    /// While enabled, the channel is synchronized on the next send after the
+    /// STA (re-)connected, e.g. after roaming, and [EspNow::set_channel]
+    /// returns an error.
+    pub fn follow_sta_channel(&self, enable: bool) -> Result<(), EspNowError> {
+        self.manager.follow_sta_channel(enable)
+    }
+
     /// Get the version of ESPNOW
     pub fn get_version(&self) -> Result<u32, EspNowError> {
         self.manager.get_version()
@@ -650,6 +938,14 @@ impl<'d> EspNow<'d> {
         self.manager.add_peer(peer)
     }
 
+    /// Add a peer to the list of known peers unless it is already known
+    ///
+    /// Returns `Ok(true)` if the peer was added and `Ok(false)` if it already
+    /// existed.
+    pub fn add_peer_if_absent(&self, peer: PeerInfo) -> Result<bool, EspNowError> {
+        self.manager.add_peer_if_absent(peer)
+    }
+
     /// Remove the given peer
     pub fn remove_peer(&self, peer_address: &[u8; 6]) -> Result<(), EspNowError> {
         self.manager.remove_peer(peer_address)
@@ -674,11 +970,23 @@ impl<'d> EspNow<'d> {
         self.manager.fetch_peer(from_head)
     }
 
+    /// Iterate over the peer list
+    ///
+    /// See [EspNowManager::peers].
+    pub fn peers(&self) -> impl Iterator<Item = PeerInfo> + '_ {
+        self.manager.peers()
+    }
+
     /// Check is peer is known
     pub fn peer_exists(&self, peer_address: &[u8; 6]) -> bool {
         self.manager.peer_exists(peer_address)
     }
 
+    /// Get the MAC address of the given interface
+    pub fn interface_mac(&self, interface: EspNowWifiInterface) -> Result<[u8; 6], EspNowError> {
+        self.manager.interface_mac(interface)
+    }
+
     /// Get the number of peers
     pub fn peer_count(&self) -> Result<PeerCount, EspNowError> {
         self.manager.peer_count()
@@ -702,6 +1010,20 @@ impl<'d> EspNow<'d> {
         self.manager.set_rate(rate)
     }
 
+    /// Automatically select the PHY rate based on the RSSI of frames received
+    /// from the given peer
+    ///
+    /// See [EspNowManager::enable_rate_adaptation].
+    pub fn enable_rate_adaptation(&self, peer: [u8; 6]) {
+        self.manager.enable_rate_adaptation(peer)
+    }
+
+    /// Disable the rate adaptation enabled by
+    /// [EspNow::enable_rate_adaptation]
+    pub fn disable_rate_adaptation(&self) {
+        self.manager.disable_rate_adaptation()
+    }
+
     /// Send data to peer
     ///
     /// The peer needs to be added to the peer list first.
@@ -713,22 +1035,173 @@ impl<'d> EspNow<'d> {
         self.sender.send(dst_addr, data)
     }
 
+    /// Send data to all devices in range
+    ///
+    /// See [EspNowSender::broadcast].
+    pub fn broadcast<'s>(&'s mut self, data: &[u8]) -> Result<SendWaiter<'s>, EspNowError> {
+        self.sender.broadcast(data)
+    }
+
+    /// Send data to peer without waiting for the sending to complete
+    ///
+    /// See [EspNowSender::send_nowait].
+    pub fn send_nowait(&mut self, dst_addr: &[u8; 6], data: &[u8]) -> Result<(), EspNowError> {
+        self.sender.send_nowait(dst_addr, data)
+    }
+
+    /// Send data to a peer, adding it to the peer list if it's unknown
+    ///
+    /// See [EspNowSender::send_auto].
+    pub fn send_auto(
+        &mut self,
+        dst_addr: &[u8; 6],
+        data: &[u8],
+        remove_after: bool,
+    ) -> Result<(), EspNowError> {
+        self.sender.send_auto(dst_addr, data, remove_after)
+    }
+
+    /// Register a callback which is notified about the result of every
+    /// sending
+    ///
+    /// See [EspNowSender::on_send_result].
+    pub fn on_send_result(&mut self, callback: Option<fn([u8; 6], bool)>) {
+        self.sender.on_send_result(callback)
+    }
+
     /// Receive data
     pub fn receive(&self) -> Option<ReceivedData> {
         self.receiver.receive()
     }
+
+    /// Number of received frames waiting to be read.
+    pub fn pending(&self) -> usize {
+        self.receiver.pending()
+    }
+}
+
+fn add_peer(peer: PeerInfo) -> Result<(), EspNowError> {
+    let raw_peer = esp_now_peer_info_t {
+        peer_addr: peer.peer_address,
+        lmk: peer.lmk.unwrap_or([0u8; 16]),
+        channel: peer.channel.unwrap_or(0),
+        ifidx: wifi_interface_t_WIFI_IF_STA,
+        encrypt: peer.encrypt,
+        priv_: core::ptr::null_mut(),
+    };
+    check_error!({ esp_now_add_peer(&raw_peer as *const _) })
+}
+
+fn add_peer_if_absent(peer: PeerInfo) -> Result<bool, EspNowError> {
+    match add_peer(peer) {
+        Ok(()) => Ok(true),
+        Err(EspNowError::Error(Error::PeerExists)) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+fn remove_peer(peer_address: &[u8; 6]) -> Result<(), EspNowError> {
+    check_error!({ esp_now_del_peer(peer_address.as_ptr()) })
+}
+
+/// Apply settings which can't be applied from the WiFi driver's callbacks
+/// before starting a new sending.
+fn prepare_send() -> Result<(), EspNowError> {
+    sync_sta_channel()?;
+    apply_adapted_rate()
+}
+
+/// Select the rate for the RSSI of a frame received from `src`
+fn adapt_rate(cs: critical_section::CriticalSection<'_>, src: &[u8; 6], rssi: i32) {
+    if *RATE_ADAPTATION_PEER.borrow_ref(cs) != Some(*src) {
+        return;
+    }
+
+    let level = RATE_ADAPTATION_LEVEL.load(Ordering::Relaxed) as usize;
+    let mut new_level = level;
+
+    while new_level > 0 && rssi < RATE_ADAPTATION_TABLE[new_level].0 {
+        new_level -= 1;
+    }
+
+    while new_level + 1 < RATE_ADAPTATION_TABLE.len()
+        && rssi >= RATE_ADAPTATION_TABLE[new_level + 1].0 + RATE_ADAPTATION_HYSTERESIS
+    {
+        new_level += 1;
+    }
+
+    if new_level != level {
+        RATE_ADAPTATION_LEVEL.store(new_level as u8, Ordering::Relaxed);
+        RATE_ADAPTATION_PENDING.store(true, Ordering::Release);
+    }
+}
+
+/// Apply the rate selected by the rate adaptation, if it changed
+fn apply_adapted_rate() -> Result<(), EspNowError> {
+    if !RATE_ADAPTATION_PENDING.swap(false, Ordering::AcqRel) {
+        return Ok(());
+    }
+
+    let level = RATE_ADAPTATION_LEVEL.load(Ordering::Relaxed) as usize;
+    let rate = RATE_ADAPTATION_TABLE[level].1;
+    check_error!({ esp_wifi_config_espnow_rate(wifi_interface_t_WIFI_IF_STA, rate) })
+}
+
+/// Called by the WiFi event handler when the STA connected to an AP
+pub(crate) fn on_sta_connected() {
+    STA_CHANNEL_SYNC_PENDING.store(true, Ordering::Release);
+}
+
+/// Switch to the channel of the associated AP if following the STA channel is
+/// enabled and the STA (re-)connected since the last synchronization.
+///
+/// This isn't done from the event handler directly since the WiFi driver must
+/// not be called from its own task.
+fn sync_sta_channel() -> Result<(), EspNowError> {
+    if !FOLLOW_STA_CHANNEL.load(Ordering::Acquire)
+        || !STA_CHANNEL_SYNC_PENDING.swap(false, Ordering::AcqRel)
+    {
+        return Ok(());
+    }
+
+    let mut ap_info: wifi_ap_record_t = unsafe { core::mem::zeroed() };
+    if unsafe { esp_wifi_sta_get_ap_info(&mut ap_info) } != 0 {
+        // Not associated (yet), the next connect will trigger a new sync
+        return Ok(());
+    }
+
+    check_error!({ esp_wifi_set_channel(ap_info.primary, ap_info.second) })
 }
 
-unsafe extern "C" fn send_cb(_mac_addr: *const u8, status: esp_now_send_status_t) {
-    critical_section::with(|_| {
+unsafe extern "C" fn send_cb(mac_addr: *const u8, status: esp_now_send_status_t) {
+    let callback = critical_section::with(|cs| {
         let is_success = status == esp_now_send_status_t_ESP_NOW_SEND_SUCCESS;
-        ESP_NOW_SEND_STATUS.store(is_success, Ordering::Relaxed);
 
-        ESP_NOW_SEND_CB_INVOKED.store(true, Ordering::Release);
+        // Sendings complete in order, so a pending `send_nowait` sending is
+        // the one this callback belongs to
+        let nowait = ESP_NOW_NOWAIT_PENDING
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| n.checked_sub(1))
+            .is_ok();
 
-        #[cfg(feature = "async")]
-        asynch::ESP_NOW_TX_WAKER.wake();
-    })
+        if !nowait {
+            ESP_NOW_SEND_STATUS.store(is_success, Ordering::Relaxed);
+
+            ESP_NOW_SEND_CB_INVOKED.store(true, Ordering::Release);
+
+            #[cfg(feature = "async")]
+            asynch::ESP_NOW_TX_WAKER.wake();
+        }
+
+        SEND_RESULT_CALLBACK
+            .borrow_ref(cs)
+            .map(|callback| (callback, is_success))
+    });
+
+    if let Some((callback, is_success)) = callback {
+        let mut dst = [0u8; 6];
+        core::ptr::copy_nonoverlapping(mac_addr, dst.as_mut_ptr(), dst.len());
+        callback(dst, is_success);
+    }
 }
 
 unsafe extern "C" fn rcv_cb(
@@ -806,9 +1279,14 @@ unsafe extern "C" fn rcv_cb(
         src_address: src,
         dst_address: dst,
         rx_control,
+        timestamp: fugit::Instant::<u64, 1, 1_000_000>::from_ticks(
+            crate::timer::ticks_to_micros(crate::timer::get_systimer_count()),
+        ),
     };
     let slice = core::slice::from_raw_parts(data, data_len as usize);
     critical_section::with(|cs| {
+        adapt_rate(cs, &info.src_address, info.rx_control.rssi);
+
         let mut queue = RECEIVE_QUEUE.borrow_ref_mut(cs);
         let mut data = [0u8; 256];
         data[..slice.len()].copy_from_slice(slice);
@@ -829,13 +1307,15 @@ unsafe extern "C" fn rcv_cb(
 }
 
 #[cfg(feature = "async")]
-pub use asynch::SendFuture;
+pub use asynch::{ReceiveFromFuture, SendFuture};
 
 #[cfg(feature = "async")]
 mod asynch {
     use core::task::{Context, Poll};
 
+    use embassy_futures::select::{select, Either};
     use embassy_sync::waitqueue::AtomicWaker;
+    use embassy_time::Timer;
 
     use super::*;
 
@@ -849,6 +1329,42 @@ mod asynch {
         pub fn receive_async(&mut self) -> ReceiveFuture<'_> {
             ReceiveFuture(PhantomData)
         }
+
+        /// Wait for data sent by the given peer.
+        ///
+        /// Frames received from other peers are put back into the receive
+        /// queue (keeping their order) by default, so they can still be
+        /// obtained by a later call to `receive` or `receive_async`. Use
+        /// [`ReceiveFromFuture::drop_unmatched`] to discard them instead.
+        ///
+        /// This function takes mutable reference to self because the
+        /// implementation of `ReceiveFromFuture` is not logically thread
+        /// safe.
+        #[must_use]
+        pub fn receive_from(&mut self, peer: [u8; 6]) -> ReceiveFromFuture<'_> {
+            ReceiveFromFuture {
+                _receiver: PhantomData,
+                peer,
+                drop_unmatched: false,
+            }
+        }
+
+        /// Wait for data to be received, giving up after `timeout`.
+        ///
+        /// Returns `None` if no data was received in time.
+        ///
+        /// This function takes mutable reference to self because the
+        /// implementation of `ReceiveFuture` is not logically thread
+        /// safe.
+        pub async fn receive_async_timeout(
+            &mut self,
+            timeout: embassy_time::Duration,
+        ) -> Option<ReceivedData> {
+            match select(self.receive_async(), Timer::after(timeout)).await {
+                Either::First(data) => Some(data),
+                Either::Second(()) => None,
+            }
+        }
     }
 
     impl<'d> EspNowSender<'d> {
@@ -864,6 +1380,13 @@ mod asynch {
                 sent: false,
             }
         }
+
+        /// Send data to all devices in range
+        ///
+        /// See [EspNowSender::broadcast].
+        pub fn broadcast_async<'s, 'r>(&'s mut self, data: &'r [u8]) -> SendFuture<'s, 'r> {
+            self.send_async(&BROADCAST_ADDRESS, data)
+        }
     }
 
     impl<'d> EspNow<'d> {
@@ -875,6 +1398,24 @@ mod asynch {
             self.receiver.receive_async()
         }
 
+        /// Wait for data sent by the given peer.
+        ///
+        /// See [`EspNowReceiver::receive_from`] for details.
+        #[must_use]
+        pub fn receive_from(&mut self, peer: [u8; 6]) -> ReceiveFromFuture<'_> {
+            self.receiver.receive_from(peer)
+        }
+
+        /// Wait for data to be received, giving up after `timeout`.
+        ///
+        /// See [`EspNowReceiver::receive_async_timeout`] for details.
+        pub async fn receive_async_timeout(
+            &mut self,
+            timeout: embassy_time::Duration,
+        ) -> Option<ReceivedData> {
+            self.receiver.receive_async_timeout(timeout).await
+        }
+
         /// The returned future must not be dropped before it's ready to avoid
         /// getting wrong status for sendings.
         #[must_use]
@@ -885,6 +1426,15 @@ mod asynch {
         ) -> SendFuture<'s, 'r> {
             self.sender.send_async(dst_addr, data)
         }
+
+        /// Send data to all devices in range
+        ///
+        /// The returned future must not be dropped before it's ready to avoid
+        /// getting wrong status for sendings.
+        #[must_use]
+        pub fn broadcast_async<'s, 'r>(&'s mut self, data: &'r [u8]) -> SendFuture<'s, 'r> {
+            self.sender.broadcast_async(data)
+        }
     }
 
     pub struct SendFuture<'s, 'r> {
@@ -900,6 +1450,9 @@ mod asynch {
         fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
             if !self.sent {
                 ESP_NOW_TX_WAKER.register(cx.waker());
+                if let Err(e) = prepare_send() {
+                    return Poll::Ready(Err(e));
+                }
                 ESP_NOW_SEND_CB_INVOKED.store(false, Ordering::Release);
                 if let Err(e) = check_error!({
                     esp_now_send(self.addr.as_ptr(), self.data.as_ptr(), self.data.len())
@@ -942,4 +1495,59 @@ mod asynch {
             }
         }
     }
+
+    /// Resolves to the next frame received from a specific peer.
+    ///
+    /// It's not logically safe to poll this future together with other
+    /// receive futures since the callback can only wake one future, leaving
+    /// the rest of them unwakable.
+    pub struct ReceiveFromFuture<'r> {
+        _receiver: PhantomData<&'r mut EspNowReceiver<'r>>,
+        peer: [u8; 6],
+        drop_unmatched: bool,
+    }
+
+    impl<'r> ReceiveFromFuture<'r> {
+        /// Discard frames received from other peers instead of putting them
+        /// back into the receive queue.
+        pub fn drop_unmatched(mut self) -> Self {
+            self.drop_unmatched = true;
+            self
+        }
+    }
+
+    impl<'r> core::future::Future for ReceiveFromFuture<'r> {
+        type Output = ReceivedData;
+
+        fn poll(self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            ESP_NOW_RX_WAKER.register(cx.waker());
+
+            if let Some(data) = critical_section::with(|cs| {
+                let mut queue = RECEIVE_QUEUE.borrow_ref_mut(cs);
+                let mut found = None;
+
+                // Walk the whole queue once so frames which are put back keep
+                // their order.
+                for _ in 0..queue.len() {
+                    let data = unwrap!(queue.dequeue());
+
+                    if data.info.src_address != self.peer {
+                        if !self.drop_unmatched {
+                            unwrap!(queue.enqueue(data));
+                        }
+                    } else if found.is_none() {
+                        found = Some(data);
+                    } else {
+                        unwrap!(queue.enqueue(data));
+                    }
+                }
+
+                found
+            }) {
+                Poll::Ready(data)
+            } else {
+                Poll::Pending
+            }
+        }
+    }
 }
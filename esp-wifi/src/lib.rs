@@ -33,6 +33,8 @@ mod binary {
 mod compat;
 mod preempt;
 
+pub use preempt::{task_stack_watermark, task_stats};
+
 mod timer;
 
 #[cfg(feature = "wifi")]
@@ -116,6 +118,8 @@ struct Config {
     failure_retry_cnt: u8,
     #[default(0)]
     scan_method: u32,
+    #[default(10)]
+    esp_now_rx_queue_size: usize,
 }
 
 // Validate the configuration at compile time
@@ -128,6 +132,10 @@ const _: () = {
         "WiFi configuration check: rx_ba_win should not be larger than dynamic_rx_buf_num!"
     );
     core::assert!(CONFIG.rx_ba_win < (CONFIG.static_rx_buf_num * 2), "WiFi configuration check: rx_ba_win should not be larger than double of the static_rx_buf_num!");
+    core::assert!(
+        CONFIG.esp_now_rx_queue_size >= 1,
+        "WiFi configuration check: esp_now_rx_queue_size must be at least 1!"
+    );
 };
 
 const HEAP_SIZE: usize = crate::CONFIG.heap_size;
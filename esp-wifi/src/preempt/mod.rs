@@ -1,3 +1,5 @@
+use core::ptr::addr_of_mut;
+
 macro_rules! sum {
     ($h:expr) => ($h);
     ($h:expr, $($t:expr),*) =>
@@ -37,6 +39,62 @@ pub fn current_task() -> usize {
     unsafe { CTX_NOW }
 }
 
+/// Pattern the task stacks are filled with when a task is created
+const STACK_PAINT: u8 = 0xa5;
+
+fn task_stack_bottom(task: usize) -> *mut u8 {
+    unsafe { (addr_of_mut!(TASK_STACK) as *mut u8).add(TASK_STACK_SIZE[task] * task) }
+}
+
+/// Fill the stack of the given task with [STACK_PAINT] to allow measuring its
+/// usage later on.
+fn paint_task_stack(task: usize) {
+    unsafe {
+        core::ptr::write_bytes(task_stack_bottom(task), STACK_PAINT, TASK_STACK_SIZE[task]);
+    }
+}
+
+/// Returns the minimum amount of stack, in bytes, the task had left so far
+///
+/// This is the number of bytes at the bottom of the task's stack which were
+/// never written. Returns `None` for the user program, whose stack isn't
+/// managed by the scheduler, or if the task doesn't exist.
+pub fn task_stack_watermark(task: usize) -> Option<usize> {
+    if task >= TASK_COUNT || task + 1 >= unsafe { TASK_TOP } {
+        return None;
+    }
+
+    let bottom = task_stack_bottom(task);
+    let untouched = (0..TASK_STACK_SIZE[task])
+        .take_while(|&offset| unsafe { bottom.add(offset).read_volatile() } == STACK_PAINT)
+        .count();
+
+    Some(untouched)
+}
+
+/// Number of timer ticks each task was running when the time slice ended
+static mut TASK_TICKS: [u64; MAX_TASK] = [0; MAX_TASK];
+
+/// Account the current time slice to the running task.
+///
+/// Only to be called from the time slice interrupt right before
+/// `task_switch`, which is never preempted by itself.
+pub(crate) fn account_tick() {
+    unsafe {
+        TASK_TICKS[CTX_NOW] += 1;
+    }
+}
+
+/// Returns the number of time slices each task used up so far
+///
+/// The items are the id of the task's context slot and the number of timer
+/// ticks the task was running when the scheduler preempted it.
+/// Time slices given up early via yielding are not counted.
+pub fn task_stats() -> impl Iterator<Item = (usize, u64)> {
+    let task_count = unsafe { TASK_TOP };
+    (0..task_count).map(|id| (id, critical_section::with(|_| unsafe { TASK_TICKS[id] })))
+}
+
 #[cfg(coex)]
 task_stack!(8192, 8192, 8192);
 
@@ -53,6 +53,7 @@ static mut CTX_TASKS: [Context; MAX_TASK] = [Context {
 pub fn task_create(task: extern "C" fn()) {
     unsafe {
         let i = allocate_task();
+        paint_task_stack(i);
 
         CTX_TASKS[i].trap_frame.pc = task as usize;
 
@@ -70,6 +70,7 @@ static mut CTX_TASKS: [TaskContext; MAX_TASK] = [TaskContext {
 pub fn task_create(task: extern "C" fn()) {
     unsafe {
         let i = allocate_task();
+        paint_task_stack(i);
 
         CTX_TASKS[i].trap_frame.PC = task as usize as u32;
 
@@ -14,7 +14,7 @@ use crate::{
         riscv,
         timer::systimer::{Alarm, Periodic, Target},
     },
-    preempt::preempt::task_switch,
+    preempt::{account_tick, preempt::task_switch},
 };
 
 /// The timer responsible for time slicing.
@@ -70,6 +70,7 @@ extern "C" fn systimer_target0(trap_frame: &mut TrapFrame) {
         unwrap!(ALARM0.borrow_ref_mut(cs).as_mut()).clear_interrupt();
     });
 
+    account_tick();
     task_switch(trap_frame);
 }
 
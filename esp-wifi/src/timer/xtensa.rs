@@ -12,7 +12,7 @@ use crate::{
         xtensa_lx,
         xtensa_lx_rt,
     },
-    preempt::preempt::task_switch,
+    preempt::{account_tick, preempt::task_switch},
 };
 
 /// The timer responsible for time slicing.
@@ -78,6 +78,7 @@ fn do_task_switch(context: &mut TrapFrame) {
 }
 
 extern "C" fn tg1_t0_level(context: &mut TrapFrame) {
+    account_tick();
     do_task_switch(context);
 }
 
@@ -859,6 +859,11 @@ pub unsafe extern "C" fn event_post(
 
     super::state::update_state(event);
 
+    #[cfg(feature = "esp-now")]
+    if matches!(event, WifiEvent::StaConnected) {
+        crate::esp_now::on_sta_connected();
+    }
+
     #[cfg(feature = "async")]
     event.waker().wake();
 
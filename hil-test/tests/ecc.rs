@@ -1045,4 +1045,197 @@ mod tests {
             .unwrap();
         assert_eq!(y_192, ECC_192_INV_MUL_RES);
     }
+
+    #[test]
+    #[cfg(feature = "esp32h2")]
+    fn test_ecc_mod_inverse_256(mut ctx: Context<'static>) {
+        const ECC_256_DEN: [u8; 32] = [
+            0x54, 0x3B, 0x11, 0x78, 0xC4, 0xCA, 0x52, 0xFD, 0xCC, 0x89, 0x51, 0x0F, 0xFE, 0x7D,
+            0x37, 0x83, 0x81, 0xD5, 0x2E, 0x58, 0x42, 0xF9, 0x4F, 0x19, 0x9A, 0x79, 0x78, 0x98,
+            0xFA, 0x95, 0x40, 0x2E,
+        ];
+
+        const ECC_256_INV_RES: [u8; 32] = [
+            0xD4, 0x3E, 0x2F, 0x07, 0xDE, 0xA5, 0x92, 0xCB, 0xB2, 0x48, 0x09, 0x0A, 0x34, 0x48,
+            0xAB, 0x49, 0x99, 0x4A, 0x61, 0xA9, 0x73, 0x9F, 0x4A, 0xC8, 0xE8, 0xFD, 0xF9, 0x22,
+            0xA6, 0x04, 0x14, 0x01,
+        ];
+
+        let mut a_256 = ECC_256_DEN.clone();
+        ctx.ecc.mod_inverse(&EllipticCurve::P256, &mut a_256).unwrap();
+        assert_eq!(a_256, ECC_256_INV_RES);
+    }
+
+    #[test]
+    fn test_ecc_jacobian_to_affine(mut ctx: Context<'static>) {
+        for &prime_field in TEST_PARAMS_VECTOR.prime_fields {
+            let t1 = &mut [0_u8; 96];
+            let (k, x) = t1.split_at_mut(prime_field.len());
+            let (x, y) = x.split_at_mut(prime_field.len());
+            let (y, _) = y.split_at_mut(prime_field.len());
+            for _ in 0..TEST_PARAMS_VECTOR.nb_loop_mul {
+                let t2 = &mut [0_u8; 96];
+
+                let (sw_k, ax) = t2.split_at_mut(prime_field.len());
+                let (ax, ay) = ax.split_at_mut(prime_field.len());
+                let (ay, _) = ay.split_at_mut(prime_field.len());
+
+                loop {
+                    ctx.rng.read(k);
+                    let is_zero = k.iter().all(|&elt| elt == 0);
+                    let is_modulus = k.iter().zip(prime_field).all(|(&a, &b)| a == b);
+                    if is_zero == false && is_modulus == false {
+                        break;
+                    }
+                }
+                sw_k.copy_from_slice(k);
+                let curve = match prime_field.len() {
+                    24 => {
+                        x.copy_from_slice(
+                            p192::AffinePoint::GENERATOR
+                                .to_encoded_point(false)
+                                .x()
+                                .unwrap(),
+                        );
+                        y.copy_from_slice(
+                            p192::AffinePoint::GENERATOR
+                                .to_encoded_point(false)
+                                .y()
+                                .unwrap(),
+                        );
+                        &EllipticCurve::P192
+                    }
+                    32 => {
+                        x.copy_from_slice(
+                            p256::AffinePoint::GENERATOR
+                                .to_encoded_point(false)
+                                .x()
+                                .unwrap(),
+                        );
+                        y.copy_from_slice(
+                            p256::AffinePoint::GENERATOR
+                                .to_encoded_point(false)
+                                .y()
+                                .unwrap(),
+                        );
+                        &EllipticCurve::P256
+                    }
+                    _ => unimplemented!(),
+                };
+
+                // `k` holds the Jacobian Z coordinate afterwards
+                ctx.ecc
+                    .jacobian_point_multiplication(curve, k, x, y)
+                    .expect("Inputs data doesn't match the key length selected.");
+                ctx.ecc
+                    .jacobian_to_affine(curve, x, y, k, ax, ay)
+                    .expect("Inputs data doesn't match the key length selected.");
+
+                let t3 = &mut [0_u8; 64];
+
+                let (sw_x, sw_y) = t3.split_at_mut(prime_field.len());
+                let (sw_y, _) = sw_y.split_at_mut(prime_field.len());
+
+                match prime_field.len() {
+                    24 => {
+                        let sw_k = p192::Scalar::from(
+                            elliptic_curve::ScalarPrimitive::from_slice(sw_k).unwrap(),
+                        );
+                        let q = p192::AffinePoint::GENERATOR
+                            .mul(sw_k)
+                            .to_affine()
+                            .to_encoded_point(false);
+                        sw_x.copy_from_slice(q.x().unwrap().as_slice());
+                        sw_y.copy_from_slice(q.y().unwrap().as_slice());
+                    }
+                    32 => {
+                        let sw_k = p256::Scalar::from(
+                            elliptic_curve::ScalarPrimitive::from_slice(sw_k).unwrap(),
+                        );
+                        let q = p256::AffinePoint::GENERATOR
+                            .mul(sw_k)
+                            .to_affine()
+                            .to_encoded_point(false);
+                        sw_x.copy_from_slice(q.x().unwrap().as_slice());
+                        sw_y.copy_from_slice(q.y().unwrap().as_slice());
+                    }
+                    _ => unimplemented!(),
+                };
+
+                for (a, b) in ax.iter().zip(sw_x) {
+                    assert_eq!(a, b);
+                }
+
+                for (a, b) in ay.iter().zip(sw_y) {
+                    assert_eq!(a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ecc_ecdh(mut ctx: Context<'static>) {
+        // NIST CAVS ECC CDH primitive test vector, P-256, COUNT = 0
+        let private = hex!("7d7dc5f71eb29ddaf80d6214632eeae03d9058af1fb6d22ed80badb62bc1a534");
+        let their_x = hex!("700c48f77f56584c5cc632ca65640db91b6bacce3a4df6b42ce7cc838833d287");
+        let their_y = hex!("db71e509e3fd9b060ddb20ba5c51dcc5948d46fbf640dfe0441782cab85fa4ac");
+        let expected = hex!("46fc62106420ff012e54a434fbdd2d25ccc5852060561e68040dd7778997bd7b");
+
+        let mut shared = [0_u8; 32];
+        ctx.ecc
+            .ecdh(
+                &EllipticCurve::P256,
+                &private,
+                &their_x,
+                &their_y,
+                &mut shared,
+            )
+            .expect("ECDH failed");
+
+        assert_eq!(shared, expected);
+    }
+
+    #[test]
+    fn test_ecc_ecdsa_verify(mut ctx: Context<'static>) {
+        let qx = hex!("b7e08afdfe94bad3f1dc8c734798ba1c62b3a0ad1e9ea2a38201cd0889bc7a19");
+        let qy = hex!("3603f747959dbf7a4bb226e41928729063adc7ae43529e61b563bbc606cc5e09");
+        // SHA-256("hello")
+        let mut hash = hex!("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+        let r = hex!("65b5ef3e5c2f0500beb39129119c4ff795a16d5ae8867754d981460bd3266c5b");
+        let s = hex!("addfacc8974e2e57ca7954d82bf72db579ea44c157753854c4c25b2c537b8fbe");
+
+        let valid = ctx
+            .ecc
+            .ecdsa_verify(&EllipticCurve::P256, (&qx, &qy), &hash, &r, &s)
+            .expect("ECDSA verification failed");
+        assert!(valid);
+
+        hash[0] ^= 1;
+        let valid = ctx
+            .ecc
+            .ecdsa_verify(&EllipticCurve::P256, (&qx, &qy), &hash, &r, &s)
+            .expect("ECDSA verification failed");
+        assert!(!valid);
+    }
+
+    #[test]
+    fn test_ecc_decompress_point(mut ctx: Context<'static>) {
+        let qy = hex!("3603f747959dbf7a4bb226e41928729063adc7ae43529e61b563bbc606cc5e09");
+        let mut compressed =
+            hex!("03b7e08afdfe94bad3f1dc8c734798ba1c62b3a0ad1e9ea2a38201cd0889bc7a19");
+        let mut y = [0_u8; 32];
+
+        ctx.ecc
+            .decompress_point(&EllipticCurve::P256, &compressed, &mut y)
+            .expect("Point decompression failed");
+        assert_eq!(y, qy);
+
+        // the other root is the negated y coordinate
+        compressed[0] = 0x02;
+        ctx.ecc
+            .decompress_point(&EllipticCurve::P256, &compressed, &mut y)
+            .expect("Point decompression failed");
+        assert!(y != qy);
+        assert_eq!(y[31] & 1, 0);
+    }
 }
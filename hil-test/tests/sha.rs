@@ -97,6 +97,49 @@ mod tests {
         assert_eq!(expected_output, output);
     }
 
+    #[test]
+    fn test_sha_256_oneshot() {
+        let peripherals = Peripherals::take();
+        #[cfg(not(feature = "esp32"))]
+        let mut sha = Sha::new(peripherals.SHA, ShaMode::SHA256, None);
+        #[cfg(feature = "esp32")]
+        let mut sha = Sha::new(peripherals.SHA, ShaMode::SHA256);
+
+        let source_data = "HELLO, ESPRESSIF!".as_bytes();
+        let expected_output = [
+            0x4e, 0x7e, 0xd9, 0xc0, 0x2d, 0xdc, 0x57, 0xb8, 0x7b, 0xee, 0x40, 0x68, 0x50, 0xa3,
+            0x74, 0x73, 0x9d, 0x10, 0x41, 0x4e, 0x3c, 0x3d, 0x55, 0x1a, 0x4c, 0xf7, 0xf1, 0x34,
+            0xe8, 0x11, 0xc9, 0xd2,
+        ];
+        let mut output = [0u8; 32];
+
+        sha.hash_oneshot(source_data, &mut output);
+        assert_eq!(expected_output, output);
+
+        // the peripheral is ready for the next hash right away
+        sha.hash_oneshot(source_data, &mut output);
+        assert_eq!(expected_output, output);
+    }
+
+    #[test]
+    #[cfg(all(not(feature = "esp32"), feature = "async"))]
+    fn test_sha_256_oneshot_async() {
+        let peripherals = Peripherals::take();
+        let mut sha = Sha::new_async(peripherals.SHA, ShaMode::SHA256);
+
+        let source_data = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".as_bytes();
+        let expected_output = [
+            0x1e, 0xbb, 0xda, 0xb3, 0x35, 0xe0, 0x54, 0x01, 0x5f, 0x0f, 0xc1, 0x7f, 0x62, 0x77,
+            0x06, 0x09, 0x72, 0x3d, 0x92, 0xc6, 0x40, 0xb6, 0x5b, 0xa9, 0x97, 0x4d, 0x66, 0x6c,
+            0x36, 0x4a, 0x3a, 0x63,
+        ];
+        let mut output = [0u8; 32];
+
+        // the input spans several blocks, each of them completes via the interrupt
+        embassy_futures::block_on(sha.hash_oneshot_async(source_data, &mut output));
+        assert_eq!(expected_output, output);
+    }
+
     #[test]
     #[cfg(any(feature = "esp32", feature = "esp32s2", feature = "esp32s3"))]
     fn test_sha_384() {
@@ -0,0 +1,74 @@
+//! TIMG Watchdog Test
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    peripherals::{Peripherals, TIMG1},
+    prelude::*,
+    system::SystemControl,
+    timer::timg::{MwdtStage, MwdtStageAction, TimerGroup, Wdt},
+    Blocking,
+};
+
+struct Context {
+    wdt: Wdt<TIMG1, Blocking>,
+    delay: Delay,
+}
+
+impl Context {
+    pub fn init() -> Self {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let timg1 = TimerGroup::new(peripherals.TIMG1, &clocks, None);
+        let delay = Delay::new(&clocks);
+
+        Context {
+            wdt: timg1.wdt,
+            delay,
+        }
+    }
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+
+    use super::*;
+
+    #[init]
+    fn init() -> Context {
+        Context::init()
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_wdt_stages_survive_enable(mut ctx: Context) {
+        ctx.wdt.set_stage_timeout(MwdtStage::Stage0, 10.millis());
+        ctx.wdt.set_stage_action(MwdtStage::Stage0, MwdtStageAction::Interrupt);
+        ctx.wdt.set_stage_timeout(MwdtStage::Stage1, 1.secs());
+        ctx.wdt.set_stage_action(MwdtStage::Stage1, MwdtStageAction::ResetSystem);
+
+        ctx.wdt.enable();
+
+        assert_eq!(ctx.wdt.stage_action(MwdtStage::Stage0), MwdtStageAction::Interrupt);
+        assert_eq!(ctx.wdt.stage_action(MwdtStage::Stage1), MwdtStageAction::ResetSystem);
+
+        ctx.delay.delay_millis(50);
+        let stage = ctx.wdt.interrupt_stage();
+
+        ctx.wdt.disable();
+        ctx.wdt.clear_interrupt();
+
+        assert_eq!(stage, Some(MwdtStage::Stage0));
+    }
+}
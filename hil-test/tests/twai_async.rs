@@ -0,0 +1,112 @@
+//! TWAI Test
+//!
+//! Following pins are used:
+//! TX    GPIO2
+//! RX    GPIO3
+//!
+//! Connect TX (GPIO2) and RX (GPIO3) pins.
+
+//% CHIPS: esp32 esp32c3 esp32c6 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    gpio::Io,
+    peripherals::{Peripherals, TWAI0},
+    system::SystemControl,
+    twai::{self, ErrorKind, EspTwaiError, TwaiConfiguration},
+    Async,
+};
+
+// More frames than fit into the 64 byte receive FIFO
+const FLOOD_FRAMES: usize = 8;
+
+struct Context {
+    twai: TwaiConfiguration<'static, TWAI0, Async>,
+}
+
+impl Context {
+    pub fn init() -> Self {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let twai = TwaiConfiguration::new_async(
+            peripherals.TWAI0,
+            io.pins.gpio2,
+            io.pins.gpio3,
+            &clocks,
+            twai::BaudRate::B125K,
+        );
+
+        Context { twai }
+    }
+}
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use defmt::assert;
+
+    use super::*;
+
+    #[init]
+    async fn init() -> Context {
+        Context::init()
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_recover_bus_off(ctx: Context) {
+        // The transmit error counter is only writable in reset mode, where the
+        // controller still is. Writing 255 simulates a real bus off caused by
+        // transmit errors, without provoking the bus errors on the wire.
+        unsafe { &*TWAI0::PTR }
+            .tx_err_cnt()
+            .write(|w| unsafe { w.tx_err_cnt().bits(255) });
+
+        let mut twai = ctx.twai.start();
+        assert!(twai.is_bus_off());
+
+        twai.recover_bus_off().await;
+        assert!(!twai.is_bus_off());
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_receive_overrun(ctx: Context) {
+        let regs = unsafe { &*TWAI0::PTR };
+
+        // In self test mode no other node needs to acknowledge the frames, and
+        // a self reception request receives the transmitted frame
+        regs.mode().modify(|_, w| w.self_test_mode().set_bit());
+
+        let mut twai = ctx.twai.start();
+
+        // The interrupts are only enabled by the first async call, so nothing
+        // drains the receive FIFO while it's flooded
+        for _ in 0..FLOOD_FRAMES {
+            regs.data_0().write(|w| unsafe { w.tx_byte_0().bits(8) });
+            regs.data_1().write(|w| unsafe { w.tx_byte_1().bits(0x24) });
+            regs.data_2().write(|w| unsafe { w.tx_byte_2().bits(0x60) });
+            regs.cmd().write(|w| w.self_rx_req().set_bit());
+
+            while regs.status().read().tx_buf_st().bit_is_clear() {}
+        }
+
+        let mut overrun = false;
+        for _ in 0..FLOOD_FRAMES {
+            if let Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)) = twai.receive_async().await {
+                overrun = true;
+                break;
+            }
+        }
+
+        assert!(overrun);
+    }
+}